@@ -0,0 +1,164 @@
+use gtk::prelude::*;
+
+use crate::i18n::{gettext, ngettext};
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Add,
+    Changed(String),
+}
+
+#[derive(Debug)]
+pub enum MsgOutput {
+    Add(String),
+}
+
+pub struct Model {
+    text: String,
+}
+
+impl Model {
+    fn preview(&self, widgets: &ModelWidgets) {
+        let tasks =
+            crate::tasks::parser::parse(&self.text, crate::application::preferences().priority);
+
+        let markup = tasks
+            .iter()
+            .map(Self::task_preview)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        widgets.preview.set_markup(&markup);
+        widgets.preview.set_visible(!tasks.is_empty());
+    }
+
+    fn task_preview(task: &crate::tasks::Task) -> String {
+        let mut details = Vec::new();
+
+        if !task.priority.is_lowest() {
+            let priority = (b'A' + u8::from(task.priority.clone())) as char;
+            details
+                .push(gettext("priority {priority}").replace("{priority}", &priority.to_string()));
+        }
+
+        for project in &task.projects {
+            details.push(format!("+{project}"));
+        }
+
+        for context in &task.contexts {
+            details.push(format!("@{context}"));
+        }
+
+        if let Some(due) = task.due_date {
+            details.push(gettext("due {date}").replace("{date}", &crate::date::format(due)));
+        }
+
+        if let Some(threshold) = task.threshold_date {
+            details.push(
+                gettext("deferred until {date}").replace("{date}", &crate::date::format(threshold)),
+            );
+        }
+
+        if let Some(recurrence) = &task.recurrence {
+            details.push(Self::recurrence(recurrence));
+        }
+
+        if task.flagged {
+            details.push(gettext("flagged"));
+        }
+
+        format!(
+            "{}\n<small>{}</small>",
+            task.markup_subject(),
+            gtk::glib::markup_escape_text(&details.join(" · "))
+        )
+    }
+
+    fn recurrence(recurrence: &todo_txt::task::Recurrence) -> String {
+        let n = recurrence.num as u32;
+        let text = match recurrence.period {
+            todo_txt::task::Period::Day => ngettext("every {n} day", "every {n} days", n),
+            todo_txt::task::Period::Week => ngettext("every {n} week", "every {n} weeks", n),
+            todo_txt::task::Period::Month => ngettext("every {n} month", "every {n} months", n),
+            todo_txt::task::Period::Year => ngettext("every {n} year", "every {n} years", n),
+        }
+        .replace("{n}", &recurrence.num.to_string());
+
+        if recurrence.strict {
+            gettext("{recurrence} from due date").replace("{recurrence}", &text)
+        } else {
+            text
+        }
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = ();
+    type Input = MsgInput;
+    type Output = MsgOutput;
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let model = Self {
+            text: String::new(),
+        };
+
+        let widgets = view_output!();
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        _: &Self::Root,
+    ) {
+        use MsgInput::*;
+
+        match msg {
+            Add => {
+                sender.output(MsgOutput::Add(self.text.clone())).ok();
+                widgets.entry.set_text("");
+            }
+            Changed(text) => {
+                self.text = text;
+                self.preview(widgets);
+            }
+        }
+    }
+
+    view! {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 5,
+
+            #[name = "entry"]
+            gtk::Entry {
+                set_width_request: 400,
+
+                connect_activate => MsgInput::Add,
+                connect_changed[sender] => move |this| {
+                    sender.input(MsgInput::Changed(this.text().to_string()));
+                },
+            },
+            gtk::Label {
+                set_text: &gettext("Create a new task +project @context due:friday !A *"),
+                set_xalign: 0.,
+            },
+            #[name = "preview"]
+            gtk::Label {
+                add_css_class: "preview",
+                set_visible: false,
+                set_wrap: true,
+                set_xalign: 0.,
+            },
+        }
+    }
+}
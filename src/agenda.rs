@@ -1,11 +1,15 @@
 use chrono::Datelike as _;
 use gtk::prelude::*;
 
+use crate::i18n::gettext;
+
 #[derive(Debug)]
 pub enum Msg {
     CalendarChange(Change),
     DateSelect(chrono::NaiveDate),
+    Sort(crate::tasks::Sort),
     Update,
+    Visibility(crate::tasks::Visibility),
 }
 
 #[derive(Debug)]
@@ -21,9 +25,7 @@ macro_rules! create {
         let component = crate::widgets::tasks::Model::builder()
             .launch(().into())
             .forward($sender.output_sender(), std::convert::identity);
-        component
-            .widget()
-            .set_vscrollbar_policy(gtk::PolicyType::Never);
+        component.emit(crate::widgets::tasks::MsgInput::MaxHeight(400));
 
         component
     }};
@@ -47,8 +49,11 @@ pub struct Model {
     date: chrono::NaiveDate,
     month: relm4::Controller<crate::widgets::tasks::Model>,
     past: relm4::Controller<crate::widgets::tasks::Model>,
+    sort: relm4::Controller<crate::widgets::sort::Model>,
     today: relm4::Controller<crate::widgets::tasks::Model>,
     tomorrow: relm4::Controller<crate::widgets::tasks::Model>,
+    visibility: crate::tasks::Visibility,
+    visibility_menu: relm4::Controller<crate::widgets::visibility::Model>,
     week: relm4::Controller<crate::widgets::tasks::Model>,
 }
 
@@ -104,10 +109,12 @@ impl Model {
         list: &crate::tasks::List,
         date: chrono::naive::NaiveDate,
     ) -> Vec<crate::tasks::Task> {
+        let preferences = crate::application::preferences();
+
         self.tasks(
             list,
             Some(date + chrono::Duration::days(2)),
-            Some(date + chrono::Duration::weeks(1)),
+            Some(date + chrono::Duration::days(preferences.agenda_week.into())),
         )
     }
 
@@ -116,10 +123,12 @@ impl Model {
         list: &crate::tasks::List,
         date: chrono::naive::NaiveDate,
     ) -> Vec<crate::tasks::Task> {
+        let preferences = crate::application::preferences();
+
         self.tasks(
             list,
-            Some(date + chrono::Duration::weeks(1)),
-            Some(date + chrono::Duration::weeks(4)),
+            Some(date + chrono::Duration::days(preferences.agenda_week.into())),
+            Some(date + chrono::Duration::days(preferences.agenda_month.into())),
         )
     }
 
@@ -129,18 +138,13 @@ impl Model {
         start: Option<chrono::naive::NaiveDate>,
         end: Option<chrono::naive::NaiveDate>,
     ) -> Vec<crate::tasks::Task> {
-        let preferences = crate::application::preferences();
+        let today = start.unwrap_or_else(crate::date::today);
 
         list.tasks
             .iter()
             .filter(|x| {
                 if let Some(due_date) = x.due_date {
-                    (preferences.done || !x.finished)
-                        && (preferences.hidden || !x.hidden)
-                        && (preferences.defered
-                            || x.threshold_date.is_none()
-                            || start.is_none()
-                            || x.threshold_date.unwrap() <= start.unwrap())
+                    self.visibility.matches(x, today)
                         && (start.is_none() || due_date >= start.unwrap())
                         && (end.is_none() || due_date < end.unwrap())
                 } else {
@@ -185,19 +189,33 @@ impl relm4::Component for Model {
         root: Self::Root,
         sender: relm4::ComponentSender<Self>,
     ) -> relm4::ComponentParts<Self> {
+        use relm4::Component as _;
         use relm4::ComponentController as _;
 
+        let sort = crate::widgets::sort::Model::builder()
+            .launch(("agenda", crate::tasks::Sort::default()))
+            .forward(sender.input_sender(), Msg::Sort);
+
+        let visibility_menu = crate::widgets::visibility::Model::builder()
+            .launch(("agenda", crate::tasks::Visibility::default()))
+            .forward(sender.input_sender(), Msg::Visibility);
+
+        let date = crate::settings::get("session", "agenda").unwrap_or(init);
+
         let model = Self {
-            date: init,
+            date,
             month: create!(sender),
             past: create!(sender),
+            sort,
             today: create!(sender),
             tomorrow: create!(sender),
+            visibility: crate::tasks::Visibility::default(),
+            visibility_menu,
             week: create!(sender),
         };
 
         let widgets = view_output!();
-        sender.input(Msg::DateSelect(init));
+        sender.input(Msg::DateSelect(date));
 
         relm4::ComponentParts { model, widgets }
     }
@@ -228,8 +246,24 @@ impl relm4::Component for Model {
                 widgets.calendar.set_month(date.month0() as i32);
                 widgets.calendar.set_year(date.year());
                 self.date = date;
+
+                crate::settings::set("session", "agenda", date);
+            }
+            Sort(sort) => {
+                use relm4::ComponentController as _;
+
+                for tasks in [
+                    &self.past,
+                    &self.today,
+                    &self.tomorrow,
+                    &self.week,
+                    &self.month,
+                ] {
+                    tasks.emit(crate::widgets::tasks::MsgInput::Sort(sort));
+                }
             }
             Update => (),
+            Visibility(visibility) => self.visibility = visibility,
         }
 
         self.update_tasks(widgets);
@@ -258,9 +292,16 @@ impl relm4::Component for Model {
                     connect_prev_year => Msg::CalendarChange(Change::PrevYear),
                 },
                 gtk::Button {
-                    set_label: "Today",
+                    set_label: &gettext("Today"),
                     connect_clicked => Msg::DateSelect(crate::date::today()),
                 },
+                gtk::Box {
+                    set_halign: gtk::Align::End,
+                    set_orientation: gtk::Orientation::Horizontal,
+
+                    append: model.visibility_menu.widget(),
+                    append: model.sort.widget(),
+                },
             },
             gtk::ScrolledWindow {
                 gtk::Box {
@@ -270,31 +311,31 @@ impl relm4::Component for Model {
                     #[name = "past_exp"]
                     gtk::Expander {
                         set_child: Some(model.past.widget()),
-                        set_label: Some("Past due"),
+                        set_label: Some(&gettext("Past due")),
                         set_vexpand: false,
                     },
                     #[name = "today_exp"]
                     gtk::Expander {
                         set_child: Some(model.today.widget()),
-                        set_label: Some("Today"),
+                        set_label: Some(&gettext("Today")),
                         set_vexpand: false,
                     },
                     #[name = "tomorrow_exp"]
                     gtk::Expander {
                         set_child: Some(model.tomorrow.widget()),
-                        set_label: Some("Tomorrow"),
+                        set_label: Some(&gettext("Tomorrow")),
                         set_vexpand: false,
                     },
                     #[name = "week_exp"]
                     gtk::Expander {
                         set_child: Some(model.week.widget()),
-                        set_label: Some("This week"),
+                        set_label: Some(&gettext("This week")),
                         set_vexpand: false,
                     },
                     #[name = "month_exp"]
                     gtk::Expander {
                         set_child: Some(model.month.widget()),
-                        set_label: Some("This month"),
+                        set_label: Some(&gettext("This month")),
                         set_vexpand: false,
                     },
                 },
@@ -28,12 +28,6 @@ pub mod tasks {
         TASKS.read().expect("Unable to rlock tasks").clone()
     }
 
-    pub fn add(text: &str) -> Result<(), String> {
-        let mut tasks = TASKS.write().expect("Unable to wlock tasks");
-
-        (*tasks).add(text)
-    }
-
     pub fn replace(new: List) {
         let mut tasks = TASKS.write().expect("Unable to wlock tasks");
 
@@ -4,45 +4,112 @@ mod preferences;
 pub use globals::preferences::get as preferences;
 pub use globals::tasks::get as tasks;
 
-use preferences::Preferences;
+pub use preferences::{Preferences, Theme};
 
 use gtk::prelude::*;
 use relm4::ComponentController as _;
 
+use crate::i18n::{gettext, ngettext};
+
 pub const NAME: &str = env!("CARGO_PKG_NAME");
 
-#[derive(Clone, Copy, Debug)]
-#[repr(u32)]
-enum Page {
-    Inbox = 0,
+pub fn load_preferences() {
+    globals::preferences::replace(Preferences::load());
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Page {
+    Inbox,
     Projects,
     Contexts,
     Tags,
     Agenda,
     Flag,
     Done,
+    Statistics,
     Search,
 }
 
-impl From<u32> for Page {
-    fn from(n: u32) -> Self {
-        match n {
-            0 => Page::Inbox,
-            1 => Page::Projects,
-            2 => Page::Contexts,
-            3 => Page::Tags,
-            4 => Page::Agenda,
-            5 => Page::Flag,
-            6 => Page::Done,
-            7 => Page::Search,
-            _ => panic!("Invalid page {n}"),
+impl Page {
+    pub const ALL: [Self; 9] = [
+        Self::Inbox,
+        Self::Projects,
+        Self::Contexts,
+        Self::Tags,
+        Self::Agenda,
+        Self::Flag,
+        Self::Done,
+        Self::Statistics,
+        Self::Search,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Page::Inbox => "inbox",
+            Page::Projects => "projects",
+            Page::Contexts => "contexts",
+            Page::Agenda => "agenda",
+            Page::Flag => "flag",
+            Page::Done => "done",
+            Page::Statistics => "statistics",
+            Page::Search => "search",
+            Page::Tags => "tags",
         }
     }
+
+    pub fn label(self) -> String {
+        match self {
+            Page::Inbox => gettext("Inbox"),
+            Page::Projects => gettext("Projects"),
+            Page::Contexts => gettext("Contexts"),
+            Page::Agenda => gettext("Agenda"),
+            Page::Flag => gettext("Flag"),
+            Page::Done => gettext("Done"),
+            Page::Statistics => gettext("Statistics"),
+            Page::Search => gettext("Search"),
+            Page::Tags => gettext("Tags"),
+        }
+    }
+
+    pub fn customizable() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter().filter(|x| *x != Page::Search)
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|page| page.name() == name)
+    }
+
+    fn order() -> Vec<Self> {
+        let saved = crate::settings::get::<String>("pages", "order").unwrap_or_default();
+        let mut pages = Vec::new();
+
+        for page in saved
+            .split(',')
+            .filter_map(|x| Self::from_name(x.trim()))
+            .chain(Self::customizable())
+        {
+            if page != Page::Search && !pages.contains(&page) {
+                pages.push(page);
+            }
+        }
+
+        pages.push(Page::Search);
+
+        pages
+    }
 }
 
-impl From<Page> for u32 {
-    fn from(page: Page) -> u32 {
-        unsafe { std::mem::transmute(page) }
+impl std::str::FromStr for Page {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| format!("Invalid page '{s}'"))
+    }
+}
+
+impl std::fmt::Display for Page {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
     }
 }
 
@@ -50,6 +117,7 @@ impl From<Page> for u32 {
 pub enum Msg {
     Adding,
     Add(String),
+    Apply(Vec<usize>, crate::tasks::Change),
     AskRefresh,
     Cancel,
     Complete(Box<crate::tasks::Task>),
@@ -57,153 +125,569 @@ pub enum Msg {
     EditCancel,
     EditDone(Box<crate::tasks::Task>),
     Find,
+    Focus(Box<crate::tasks::Task>),
     Help,
+    Notification(crate::notifications::Action, String),
+    Notify,
+    Page(Page),
+    PagesReordered,
+    Palette,
+    Preferences(Preferences),
+    Query(String),
     Refresh,
+    Retry,
+    SaveSearch(String),
     Search(String),
+    Select(Page, String),
+    ShowLogs,
+    ShowPreferences,
+    ShowStandup,
+    ThemeChanged,
+    Undo,
+    UserStyleChanged,
+}
+
+struct Write {
+    diff: crate::tasks::Diff,
+    message: String,
+    task: Option<crate::tasks::Task>,
 }
 
 pub struct Model {
+    add: relm4::Controller<crate::add::Model>,
     agenda: relm4::Controller<crate::agenda::Model>,
     config: todo_txt::Config,
     contexts: relm4::Controller<crate::widgets::tags::Model>,
+    counts: std::collections::HashMap<Page, gtk::Label>,
+    css: gtk::CssProvider,
+    css_monitor: Option<gtk::gio::FileMonitor>,
     done: relm4::Controller<crate::done::Model>,
     edit: relm4::Controller<crate::edit::Model>,
     flag: relm4::Controller<crate::flag::Model>,
+    focused: Option<crate::tasks::Task>,
     inbox: relm4::Controller<crate::inbox::Model>,
     logger: relm4::Controller<crate::logger::Model>,
+    notifier: crate::notifications::Desktop,
+    pages: Vec<Page>,
+    palette: relm4::Controller<crate::palette::Model>,
+    preferences: relm4::Controller<crate::preferences::Model>,
     projects: relm4::Controller<crate::widgets::tags::Model>,
+    retry: Option<Write>,
+    scheduler: crate::tasks::reminder::Scheduler,
     search: relm4::Controller<crate::search::Model>,
     shortcuts: gtk::ShortcutsWindow,
+    standup: relm4::Controller<crate::standup::Model>,
+    statistics: relm4::Controller<crate::statistics::Model>,
     tags: relm4::Controller<crate::widgets::tags::Model>,
+    toast: relm4::Controller<crate::toast::Model>,
+    undo: Option<crate::tasks::Diff>,
+    user_css: gtk::CssProvider,
     watcher: notify::RecommendedWatcher,
 }
 
 impl Model {
     fn load_style(&self) {
-        let css = gtk::CssProvider::new();
-        css.load_from_resource(&self.stylesheet());
+        self.css.load_from_resource(&self.stylesheet());
+    }
 
-        gtk::style_context_add_provider_for_display(
-            &gtk::gdk::Display::default().unwrap(),
-            &css,
-            0,
-        );
+    fn load_user_style(&self) {
+        let path = crate::settings::config_file("style.css");
+
+        if path.exists() {
+            self.user_css.load_from_path(&path);
+            log::debug!("User stylesheet {path:?} loaded");
+        } else {
+            self.user_css.load_from_data("");
+        }
     }
 
-    fn stylesheet(&self) -> String {
-        let mut stylesheet = "style_light.css";
+    fn apply_theme(&self) {
+        let Some(settings) = gtk::Settings::default() else {
+            return;
+        };
 
-        if let Ok(theme) = std::env::var("GTK_THEME") {
-            if theme.ends_with(":dark") {
-                stylesheet = "style_dark.css";
-            }
-        } else if let Some(setting) = gtk::Settings::default()
-            && setting.is_gtk_application_prefer_dark_theme()
-        {
-            stylesheet = "style_dark.css";
+        match preferences().theme {
+            Theme::System => settings.reset_property("gtk-application-prefer-dark-theme"),
+            Theme::Light => settings.set_gtk_application_prefer_dark_theme(false),
+            Theme::Dark => settings.set_gtk_application_prefer_dark_theme(true),
         }
+    }
+
+    fn stylesheet(&self) -> String {
+        let dark = match preferences().theme {
+            Theme::Light => false,
+            Theme::Dark => true,
+            Theme::System => match std::env::var("GTK_THEME") {
+                Ok(theme) => theme.ends_with(":dark"),
+                Err(_) => gtk::Settings::default().is_some_and(|x| {
+                    x.is_gtk_application_prefer_dark_theme()
+                        || x.gtk_theme_name()
+                            .is_some_and(|name| name.to_lowercase().ends_with("-dark"))
+                }),
+            },
+        };
+
+        let stylesheet = if dark {
+            "style_dark.css"
+        } else {
+            "style_light.css"
+        };
 
         format!("/txt/todo/effitask/{stylesheet}")
     }
 
-    fn add_tab_widgets(&self, notebook: &gtk::Notebook) {
-        let n = notebook.n_pages();
+    fn watch_style(sender: &relm4::ComponentSender<Self>) -> Option<gtk::gio::FileMonitor> {
+        if let Some(settings) = gtk::Settings::default() {
+            settings.connect_gtk_application_prefer_dark_theme_notify(gtk::glib::clone!(
+                #[strong]
+                sender,
+                move |_| sender.input(Msg::ThemeChanged)
+            ));
+            settings.connect_gtk_theme_name_notify(gtk::glib::clone!(
+                #[strong]
+                sender,
+                move |_| sender.input(Msg::ThemeChanged)
+            ));
+        }
+
+        let file = gtk::gio::File::for_path(crate::settings::config_file("style.css"));
 
-        for x in 0..n {
-            let page = notebook.nth_page(Some(x)).unwrap();
-            let widget = self.tab_widget(x);
+        let monitor = match file.monitor_file(
+            gtk::gio::FileMonitorFlags::NONE,
+            None::<&gtk::gio::Cancellable>,
+        ) {
+            Ok(monitor) => monitor,
+            Err(err) => {
+                log::warn!("Unable to watch user stylesheet: {err}");
+                return None;
+            }
+        };
 
-            notebook.set_tab_label(&page, Some(&widget));
+        let sender = sender.clone();
+        monitor.connect_changed(move |_, _, _, event| {
+            if matches!(
+                event,
+                gtk::gio::FileMonitorEvent::ChangesDoneHint
+                    | gtk::gio::FileMonitorEvent::Created
+                    | gtk::gio::FileMonitorEvent::Deleted
+            ) {
+                sender.input(Msg::UserStyleChanged);
+            }
+        });
+
+        Some(monitor)
+    }
+
+    fn add_pages(&mut self, notebook: &gtk::Notebook) {
+        for page in self.pages.clone() {
+            let widget = self.page_widget(page);
+            let (tab, count) = Self::tab_widget(page);
+
+            notebook.append_page(&widget, Some(&tab));
+            notebook.set_tab_reorderable(&widget, page != Page::Search);
+
+            if let Some(count) = count {
+                self.counts.insert(page, count);
+            }
         }
     }
 
-    fn tab_widget(&self, n: u32) -> gtk::Box {
+    fn tab_widget(page: Page) -> (gtk::Box, Option<gtk::Label>) {
         let vbox = gtk::Box::new(gtk::Orientation::Vertical, 0);
         vbox.set_homogeneous(false);
 
-        let title = match n.into() {
-            Page::Inbox => "inbox",
-            Page::Projects => "projects",
-            Page::Contexts => "contexts",
-            Page::Agenda => "agenda",
-            Page::Flag => "flag",
-            Page::Done => "done",
-            Page::Search => "search",
-            Page::Tags => "tags",
-        };
+        vbox.update_property(&[gtk::accessible::Property::Label(&page.label())]);
 
-        let image = gtk::Image::from_icon_name(title);
+        let image = gtk::Image::from_icon_name(page.name());
         image.set_icon_size(gtk::IconSize::Large);
+        image.update_state(&[gtk::accessible::State::Hidden(true)]);
         vbox.append(&image);
 
-        let label = gtk::Label::new(Some(title));
+        let label = gtk::Label::new(Some(&page.label()));
         vbox.append(&label);
 
-        vbox
+        let count = matches!(page, Page::Inbox | Page::Agenda | Page::Flag).then(|| {
+            let count = gtk::Label::new(None);
+            count.add_css_class("count");
+            count.set_visible(false);
+            count.update_state(&[gtk::accessible::State::Hidden(true)]);
+            vbox.append(&count);
+
+            count
+        });
+
+        (vbox, count)
+    }
+
+    fn page_widget(&self, page: Page) -> gtk::Widget {
+        match page {
+            Page::Inbox => self.inbox.widget().clone().upcast(),
+            Page::Projects => self.projects.widget().clone().upcast(),
+            Page::Contexts => self.contexts.widget().clone().upcast(),
+            Page::Tags => self.tags.widget().clone().upcast(),
+            Page::Agenda => self.agenda.widget().clone().upcast(),
+            Page::Flag => self.flag.widget().clone().upcast(),
+            Page::Done => self.done.widget().clone().upcast(),
+            Page::Statistics => self.statistics.widget().clone().upcast(),
+            Page::Search => self.search.widget().clone().upcast(),
+        }
+    }
+
+    fn current_page(&self, widgets: &ModelWidgets) -> Option<Page> {
+        let n = widgets.notebook.current_page()?;
+
+        self.pages.get(n as usize).copied()
+    }
+
+    fn set_page(&self, widgets: &ModelWidgets, page: Page) {
+        if let Some(n) = self.pages.iter().position(|x| *x == page) {
+            widgets.notebook.set_current_page(Some(n as u32));
+        }
+    }
+
+    fn home_page(&self) -> Page {
+        let preferences = preferences();
+        let visible = |page: &Page| !preferences.hidden_pages.contains(page);
+
+        preferences
+            .startup_page
+            .filter(visible)
+            .or_else(|| {
+                self.pages
+                    .iter()
+                    .copied()
+                    .find(|x| *x != Page::Search && visible(x))
+            })
+            .unwrap_or(Page::Inbox)
+    }
+
+    fn update_pages(&self) {
+        let hidden = preferences().hidden_pages;
+        let all_hidden = Page::customizable().all(|x| hidden.contains(&x));
+
+        for page in Page::customizable() {
+            self.page_widget(page)
+                .set_visible(all_hidden || !hidden.contains(&page));
+        }
+    }
+
+    fn reorder_pages(&mut self, widgets: &ModelWidgets) {
+        let notebook = &widgets.notebook;
+
+        self.pages = (0..notebook.n_pages())
+            .filter_map(|n| notebook.nth_page(Some(n)))
+            .filter_map(|widget| {
+                Page::ALL
+                    .into_iter()
+                    .find(|page| self.page_widget(*page) == widget)
+            })
+            .collect();
+
+        let order = self
+            .pages
+            .iter()
+            .filter(|x| **x != Page::Search)
+            .map(|x| x.name())
+            .collect::<Vec<_>>();
+
+        crate::settings::set("pages", "order", order.join(","));
+    }
+
+    fn update_counts(&self) {
+        let today = crate::date::today();
+        let list = tasks();
+        let show = preferences().tab_counts;
+
+        for (page, label) in &self.counts {
+            let (count, tooltip) = match page {
+                Page::Inbox => {
+                    let count = list
+                        .tasks
+                        .iter()
+                        .filter(|x| !x.finished && x.projects.is_empty())
+                        .count();
+
+                    (
+                        count,
+                        ngettext(
+                            "{count} task in inbox",
+                            "{count} tasks in inbox",
+                            count as u32,
+                        ),
+                    )
+                }
+                Page::Agenda => {
+                    let count = list
+                        .tasks
+                        .iter()
+                        .filter(|x| !x.finished && x.due_date.is_some_and(|due| due < today))
+                        .count();
+
+                    (
+                        count,
+                        ngettext(
+                            "{count} overdue task",
+                            "{count} overdue tasks",
+                            count as u32,
+                        ),
+                    )
+                }
+                Page::Flag => {
+                    let count = list
+                        .tasks
+                        .iter()
+                        .filter(|x| !x.finished && x.flagged)
+                        .count();
+
+                    (
+                        count,
+                        ngettext(
+                            "{count} flagged task",
+                            "{count} flagged tasks",
+                            count as u32,
+                        ),
+                    )
+                }
+                _ => continue,
+            };
+
+            Self::set_count(label, count, &tooltip, show);
+        }
+    }
+
+    fn set_count(label: &gtk::Label, count: usize, tooltip: &str, show: bool) {
+        let tooltip = tooltip.replace("{count}", &count.to_string());
+        let visible = show && count > 0;
+
+        label.set_text(&count.to_string());
+        label.set_tooltip_text(Some(&tooltip));
+        label.set_visible(visible);
+
+        if let Some(tab) = label.parent() {
+            tab.update_property(&[gtk::accessible::Property::Description(if visible {
+                &tooltip
+            } else {
+                ""
+            })]);
+        }
     }
 
     fn add(&mut self, widgets: &ModelWidgets, text: &str) {
-        self.unwatch();
-        match globals::tasks::add(text) {
-            Ok(_) => self.update_tasks(widgets),
-            Err(err) => log::error!("Unable to create task: '{err}'"),
+        use crate::toast::Toast;
+
+        let previous = tasks();
+        let mut list = previous.clone();
+
+        match list.add(text) {
+            Ok(()) => self.commit(Write {
+                diff: crate::tasks::Diff::new(&previous, &list),
+                message: gettext("Task created"),
+                task: None,
+            }),
+            Err(err) => {
+                log::error!("Unable to create task: '{err}'");
+                self.toast(Toast::error(&gettext("Unable to create task")).with_details(&err));
+            }
         }
-        self.watch();
 
         widgets.add_popover.popdown();
     }
 
-    fn complete(&mut self, widgets: &ModelWidgets, task: &crate::tasks::Task) {
+    fn apply(&mut self, ids: &[usize], change: &crate::tasks::Change) {
+        let previous = tasks();
+        let mut list = previous.clone();
+        list.apply(ids, change);
+
+        self.commit(Write {
+            diff: crate::tasks::Diff::new(&previous, &list),
+            message: ngettext(
+                "{count} task updated",
+                "{count} tasks updated",
+                ids.len() as u32,
+            )
+            .replace("{count}", &ids.len().to_string()),
+            task: None,
+        });
+    }
+
+    fn complete(&mut self, task: &crate::tasks::Task) {
         let id = task.id;
-        let mut list = tasks();
+        let previous = tasks();
+        let mut list = previous.clone();
 
-        if let Some(ref mut t) = list.tasks.get_mut(id) {
-            if t.finished {
-                t.uncomplete();
-            } else {
-                t.complete();
-            }
-        } else {
+        let Some(finished) = list.tasks.get(id).map(|x| x.finished) else {
             return;
+        };
+
+        if finished {
+            list.uncomplete(id);
+        } else {
+            list.complete(id);
         }
 
-        let t = list.tasks[id].clone();
+        let message = if list.tasks[id].finished {
+            gettext("Task done")
+        } else {
+            gettext("Task undone")
+        };
+        let task = list.tasks[id].clone();
 
-        if t.finished
-            && let Some(ref recurrence) = t.recurrence
-        {
-            let due = if recurrence.strict
-                && let Some(due_date) = t.due_date
-            {
-                due_date
-            } else {
-                crate::date::today()
-            };
+        self.commit(Write {
+            diff: crate::tasks::Diff::new(&previous, &list),
+            message,
+            task: Some(task),
+        });
+    }
 
-            let mut new: crate::tasks::Task = t.clone();
-            new.uncomplete();
-            new.create_date = Some(crate::date::today());
-            new.due_date = Some(recurrence.clone() + due);
+    fn commit(&mut self, write: Write) {
+        use crate::toast::Toast;
 
-            if let Some(threshold_date) = t.threshold_date {
-                new.threshold_date = Some(recurrence.clone() + threshold_date);
-            }
+        let mut list =
+            crate::tasks::List::from_files(&self.config.todo_file, &self.config.done_file);
 
-            list.push(new);
+        if let Err(task) = write.diff.apply(&mut list) {
+            log::error!("Unable to find '{task}', tasks were modified on disk");
+
+            self.toast(
+                Toast::error(&gettext("Tasks were modified by another application"))
+                    .with_details(&task),
+            );
+            self.update_tasks();
+
+            return;
         }
 
         match self.write_tasks(&list) {
-            Ok(_) => {
-                if list.tasks[id].finished {
-                    log::info!("Task done");
-                } else {
-                    log::info!("Task undone");
+            Ok(()) => {
+                log::debug!("{}", write.message);
+
+                let mut toast =
+                    Toast::info(&write.message).with_action(&gettext("Undo"), Msg::Undo);
+
+                if let Some(task) = write.task {
+                    toast = toast.with_action(&gettext("Open task"), Msg::Edit(Box::new(task)));
                 }
+
+                self.undo = Some(write.diff.inverse());
+                self.toast(toast);
+            }
+            Err(err) => {
+                log::error!("Unable to save tasks: {err}");
+
+                self.toast(
+                    Toast::error(&gettext("Unable to save tasks"))
+                        .with_details(&err)
+                        .with_action(&gettext("Retry"), Msg::Retry),
+                );
+                self.retry = Some(write);
             }
-            Err(err) => log::error!("Unable to save tasks: {err}"),
+        }
+
+        self.update_tasks();
+    }
+
+    fn toast(&self, toast: crate::toast::Toast) {
+        self.toast
+            .emit(crate::toast::MsgInput::Show(Box::new(toast)));
+    }
+
+    fn undo(&mut self) {
+        let Some(diff) = self.undo.take() else {
+            return;
         };
 
-        self.update_tasks(widgets);
+        self.commit(Write {
+            diff,
+            message: gettext("Change undone"),
+            task: None,
+        });
+    }
+
+    fn set_preferences(&mut self, new: Preferences) {
+        let old = preferences();
+
+        log::set_max_level(crate::logger::level(new.log_level));
+        globals::preferences::replace(new.clone());
+
+        if new.theme != old.theme {
+            self.apply_theme();
+            self.load_style();
+        }
+
+        if new.hidden_pages != old.hidden_pages {
+            self.update_pages();
+        }
+
+        if (
+            &new.date_format,
+            new.week_start,
+            new.agenda_week,
+            new.agenda_month,
+        ) != (
+            &old.date_format,
+            old.week_start,
+            old.agenda_week,
+            old.agenda_month,
+        ) {
+            self.update_tasks();
+        } else if new.tab_counts != old.tab_counts {
+            self.update_counts();
+        }
+
+        if (new.notifications, &new.notification_times)
+            != (old.notifications, &old.notification_times)
+        {
+            self.notify();
+        }
+    }
+
+    fn notify(&mut self) {
+        let preferences = preferences();
+
+        if preferences.notifications {
+            self.scheduler.check(
+                &mut self.notifier,
+                &tasks().tasks,
+                &preferences.notification_times,
+                chrono::Local::now().naive_local(),
+            );
+        } else {
+            self.scheduler.clear(&mut self.notifier);
+        }
+    }
+
+    fn notification(
+        &mut self,
+        window: &gtk::ApplicationWindow,
+        action: crate::notifications::Action,
+        id: &str,
+    ) {
+        use crate::notifications::Action;
+
+        let now = chrono::Local::now().naive_local();
+        let task = tasks()
+            .tasks
+            .into_iter()
+            .find(|x| crate::tasks::reminder::id(x) == id);
+
+        match (action, task) {
+            (Action::Complete, Some(task)) => self.complete(&task),
+            (Action::Open, Some(task)) => {
+                window.present();
+                self.edit(&task);
+            }
+            (Action::SnoozeHour, _) => {
+                self.scheduler
+                    .snooze(&mut self.notifier, id, now + chrono::Duration::hours(1));
+            }
+            (Action::SnoozeDay, _) => {
+                self.scheduler
+                    .snooze(&mut self.notifier, id, now + chrono::Duration::days(1));
+            }
+            (Action::Complete | Action::Open, None) => {
+                log::warn!("Task '{id}' not found, it may have been modified");
+            }
+        }
     }
 
     fn edit(&mut self, task: &crate::tasks::Task) {
@@ -212,47 +696,176 @@ impl Model {
         self.edit.widget().set_visible(true);
     }
 
-    fn save(&mut self, widgets: &ModelWidgets, task: &crate::tasks::Task) {
+    fn save(&mut self, task: &crate::tasks::Task) {
         let id = task.id;
-        let mut list = tasks();
+        let previous = tasks();
+        let mut list = previous.clone();
 
         if list.tasks.get_mut(id).is_some() {
             list.tasks[id] = task.clone();
         }
 
-        match self.write_tasks(&list) {
-            Ok(_) => (),
-            Err(err) => log::error!("Unable to save tasks: {err}"),
-        };
-
-        log::info!("Task updated");
-
-        self.update_tasks(widgets);
+        self.commit(Write {
+            diff: crate::tasks::Diff::new(&previous, &list),
+            message: gettext("Task updated"),
+            task: None,
+        });
         self.edit.widget().set_visible(false);
     }
 
     fn search(&self, widgets: &ModelWidgets, query: &str) {
         if query.is_empty() {
-            widgets.notebook.set_current_page(Some(Page::Inbox.into()));
+            self.set_page(widgets, self.home_page());
             self.search.widget().set_visible(false);
         } else {
             self.search.widget().set_visible(true);
-            widgets.notebook.set_current_page(Some(Page::Search.into()));
+            self.set_page(widgets, Page::Search);
         }
 
         self.search
             .emit(crate::search::MsgInput::UpdateFilter(query.to_string()));
     }
 
-    fn update_tasks(&self, widgets: &ModelWidgets) {
+    fn recent_searches() -> Vec<String> {
+        crate::settings::get::<String>("searches", "recent")
+            .map(|x| x.lines().map(ToString::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    fn save_search(query: &str) {
+        let query = query.trim();
+
+        if query.is_empty() {
+            return;
+        }
+
+        let mut recent = Self::recent_searches();
+        recent.retain(|x| x != query);
+        recent.insert(0, query.to_string());
+        recent.truncate(10);
+
+        crate::settings::set("searches", "recent", recent.join("\n"));
+    }
+
+    fn select(&self, widgets: &ModelWidgets, page: Page, tag: String) {
+        let tags = match page {
+            Page::Projects => &self.projects,
+            Page::Contexts => &self.contexts,
+            Page::Tags => &self.tags,
+            _ => return,
+        };
+
+        self.set_page(widgets, page);
+        tags.emit(crate::widgets::tags::MsgInput::Select(tag));
+    }
+
+    fn commands(&self) -> Vec<crate::palette::Command> {
+        use crate::palette::Command;
+
+        let mut commands = Vec::new();
+
+        for shortcut in crate::shortcuts::group("general") {
+            match Self::shortcut_msg(shortcut.action, shortcut.target) {
+                Some(Msg::Palette) | None => (),
+                Some(msg) => commands.push(
+                    Command::new(&gettext("General"), &gettext(shortcut.title), msg)
+                        .with_accel(shortcut.triggers.first()),
+                ),
+            }
+        }
+
+        commands.push(Command::new(
+            &gettext("General"),
+            &gettext("Preferences"),
+            Msg::ShowPreferences,
+        ));
+
+        commands.push(Command::new(
+            &gettext("General"),
+            &gettext("Standup report"),
+            Msg::ShowStandup,
+        ));
+
+        commands.push(Command::new(
+            &gettext("General"),
+            &gettext("Logs"),
+            Msg::ShowLogs,
+        ));
+
+        for shortcut in crate::shortcuts::group("pages") {
+            if let Some(msg @ Msg::Page(page)) =
+                Self::shortcut_msg(shortcut.action, shortcut.target)
+                && page != Page::Search
+                && self.page_widget(page).is_visible()
+            {
+                commands.push(
+                    Command::new(&gettext("Page"), &gettext(shortcut.title), msg)
+                        .with_accel(shortcut.triggers.first()),
+                );
+            }
+        }
+
+        let list = tasks();
+        let visibility = crate::tasks::Visibility::default();
+
+        for (page, sigil, tags) in [
+            (Page::Projects, '+', list.projects(&visibility)),
+            (Page::Contexts, '@', list.contexts(&visibility)),
+            (Page::Tags, '#', list.hashtags(&visibility)),
+        ] {
+            if !self.page_widget(page).is_visible() {
+                continue;
+            }
+
+            for tag in tags {
+                commands.push(Command::new(
+                    &page.label(),
+                    &format!("{sigil}{tag}"),
+                    Msg::Select(page, tag),
+                ));
+            }
+        }
+
+        for query in Self::recent_searches() {
+            commands.push(Command::new(
+                &gettext("Search"),
+                &query,
+                Msg::Query(query.clone()),
+            ));
+        }
+
+        let Some(task) = self
+            .focused
+            .as_ref()
+            .filter(|x| list.tasks.get(x.id) == Some(*x))
+        else {
+            return commands;
+        };
+
+        for shortcut in crate::shortcuts::group("tasks") {
+            let msg = match shortcut.action {
+                "complete" => Msg::Complete(Box::new(task.clone())),
+                "edit" => Msg::Edit(Box::new(task.clone())),
+                action => match crate::tasks::Change::from_action(task, action, shortcut.target) {
+                    Some(change) => Msg::Apply(vec![task.id], change),
+                    None => continue,
+                },
+            };
+
+            commands.push(
+                Command::new(&gettext("Task"), &gettext(shortcut.title), msg)
+                    .with_accel(shortcut.triggers.first()),
+            );
+        }
+
+        commands
+    }
+
+    fn update_tasks(&self) {
         let list = crate::tasks::List::from_files(&self.config.todo_file, &self.config.done_file);
         globals::tasks::replace(list);
 
-        globals::preferences::replace(crate::application::Preferences {
-            defered: widgets.defered_button.is_active(),
-            done: widgets.done_button.is_active(),
-            hidden: widgets.hidden_button.is_active(),
-        });
+        self.update_counts();
 
         self.agenda.sender().emit(crate::agenda::Msg::Update);
         self.contexts
@@ -265,6 +878,9 @@ impl Model {
         self.flag.sender().emit(crate::flag::Msg::Update);
         self.inbox.sender().emit(crate::inbox::Msg::Update);
         self.search.sender().emit(crate::search::MsgInput::Update);
+        self.statistics
+            .sender()
+            .emit(crate::statistics::Msg::Update);
         self.tags
             .sender()
             .emit(crate::widgets::tags::MsgInput::Update);
@@ -291,36 +907,105 @@ impl Model {
             .ok();
     }
 
-    fn shortcuts(window: &gtk::ApplicationWindow, sender: relm4::ComponentSender<Self>) {
-        static SHORTCUTS: &[(&str, Msg)] = &[
-            ("<Control>A", Msg::Adding),
-            ("<Control>F", Msg::Find),
-            ("F3", Msg::Find),
-            ("<Control>R", Msg::Refresh),
-            ("F5", Msg::Refresh),
-        ];
+    fn shortcuts(
+        window: &gtk::ApplicationWindow,
+        notebook: &gtk::Notebook,
+        sender: relm4::ComponentSender<Self>,
+    ) {
+        let controller = Self::shortcut_controller("general", &sender);
+        controller.set_scope(gtk::ShortcutScope::Global);
+        window.add_controller(controller);
+
+        let controller = Self::shortcut_controller("pages", &sender);
+        notebook.add_controller(controller);
+    }
 
+    fn shortcut_controller(
+        group: &str,
+        sender: &relm4::ComponentSender<Self>,
+    ) -> gtk::ShortcutController {
         let controller = gtk::ShortcutController::new();
-        controller.set_scope(gtk::ShortcutScope::Global);
 
-        for (trigger, msg) in SHORTCUTS {
-            let trigger = gtk::ShortcutTrigger::parse_string(trigger);
-            let callback = gtk::CallbackAction::new(gtk::glib::clone!(
-                #[strong]
-                sender,
-                #[strong]
-                msg,
-                move |_, _| {
-                    sender.input(msg.clone());
-                    gtk::glib::Propagation::Stop
-                }
-            ));
+        for shortcut in crate::shortcuts::group(group) {
+            let Some(msg) = Self::shortcut_msg(shortcut.action, shortcut.target) else {
+                continue;
+            };
+
+            for trigger in &shortcut.triggers {
+                let Some(trigger) = gtk::ShortcutTrigger::parse_string(trigger) else {
+                    continue;
+                };
+
+                let callback = gtk::CallbackAction::new(gtk::glib::clone!(
+                    #[strong]
+                    sender,
+                    #[strong]
+                    msg,
+                    move |_, _| {
+                        sender.input(msg.clone());
+                        gtk::glib::Propagation::Stop
+                    }
+                ));
 
-            let shortcut = gtk::Shortcut::new(trigger, Some(callback));
-            controller.add_shortcut(shortcut);
+                let shortcut = gtk::Shortcut::new(Some(trigger), Some(callback));
+                controller.add_shortcut(shortcut);
+            }
         }
 
-        window.add_controller(controller);
+        controller
+    }
+
+    fn shortcut_msg(action: &str, target: Option<&str>) -> Option<Msg> {
+        let msg = match (action, target) {
+            ("add", _) => Msg::Adding,
+            ("find", _) => Msg::Find,
+            ("help", _) => Msg::Help,
+            ("palette", _) => Msg::Palette,
+            ("refresh", _) => Msg::Refresh,
+            ("page", Some(name)) => Msg::Page(Page::from_name(name)?),
+            _ => return None,
+        };
+
+        Some(msg)
+    }
+
+    fn restore_session(&self, window: &gtk::ApplicationWindow, widgets: &ModelWidgets) {
+        use crate::settings::get;
+
+        if let (Some(width), Some(height)) = (get("session", "width"), get("session", "height")) {
+            window.set_default_size(width, height);
+        }
+
+        if get("session", "maximized").unwrap_or(false) {
+            window.maximize();
+        }
+
+        if let Some(position) = get("session", "paned") {
+            widgets.paned.set_position(position);
+        }
+
+        let page = preferences()
+            .startup_page
+            .or_else(|| get::<String>("session", "page").and_then(|x| Page::from_name(&x)));
+
+        if let Some(page) = page
+            && page != Page::Search
+        {
+            self.set_page(widgets, page);
+        }
+    }
+
+    fn save_geometry(window: &gtk::ApplicationWindow) {
+        let (width, height) = window.default_size();
+
+        crate::settings::set_all(
+            "session",
+            &[
+                ("width", width.to_string()),
+                ("height", height.to_string()),
+                ("maximized", window.is_maximized().to_string()),
+            ],
+        );
     }
 
     fn write_tasks(&mut self, list: &crate::tasks::List) -> Result<(), String> {
@@ -330,14 +1015,6 @@ impl Model {
 
         result
     }
-
-    fn check_button_set_markup(check_button: &gtk::CheckButton) {
-        if let Some(child) = check_button.child()
-            && let Ok(label) = child.downcast::<gtk::Label>()
-        {
-            label.set_use_markup(true);
-        }
-    }
 }
 
 #[relm4::component(pub)]
@@ -352,28 +1029,43 @@ impl relm4::Component for Model {
         root: Self::Root,
         sender: relm4::ComponentSender<Self>,
     ) -> relm4::ComponentParts<Self> {
+        load_preferences();
+
         let logger = crate::logger::Model::builder().launch(()).detach();
 
+        let add =
+            crate::add::Model::builder()
+                .launch(())
+                .forward(sender.input_sender(), |output| match output {
+                    crate::add::MsgOutput::Add(text) => Msg::Add(text),
+                });
+
         let agenda = crate::agenda::Model::builder()
             .launch(crate::date::today())
             .forward(sender.input_sender(), |output| match output {
+                crate::widgets::task::MsgOutput::Apply(ids, change) => Msg::Apply(ids, change),
                 crate::widgets::task::MsgOutput::Complete(task) => Msg::Complete(task),
                 crate::widgets::task::MsgOutput::Edit(task) => Msg::Edit(task),
+                crate::widgets::task::MsgOutput::Focus(task) => Msg::Focus(task),
             });
 
         let contexts = crate::widgets::tags::Model::builder()
             .launch(crate::widgets::tags::Type::Contexts)
             .forward(sender.input_sender(), |output| match output {
+                crate::widgets::tags::MsgOutput::Apply(ids, change) => Msg::Apply(ids, change),
                 crate::widgets::tags::MsgOutput::Complete(task) => Msg::Complete(task),
                 crate::widgets::tags::MsgOutput::Edit(task) => Msg::Edit(task),
+                crate::widgets::tags::MsgOutput::Focus(task) => Msg::Focus(task),
             });
 
         let done =
             crate::done::Model::builder()
                 .launch(())
                 .forward(sender.input_sender(), |output| match output {
+                    crate::widgets::task::MsgOutput::Apply(ids, change) => Msg::Apply(ids, change),
                     crate::widgets::task::MsgOutput::Complete(task) => Msg::Complete(task),
                     crate::widgets::task::MsgOutput::Edit(task) => Msg::Edit(task),
+                    crate::widgets::task::MsgOutput::Focus(task) => Msg::Focus(task),
                 });
 
         let edit = crate::edit::Model::builder()
@@ -387,41 +1079,72 @@ impl relm4::Component for Model {
             crate::flag::Model::builder()
                 .launch(())
                 .forward(sender.input_sender(), |output| match output {
+                    crate::widgets::task::MsgOutput::Apply(ids, change) => Msg::Apply(ids, change),
                     crate::widgets::task::MsgOutput::Complete(task) => Msg::Complete(task),
                     crate::widgets::task::MsgOutput::Edit(task) => Msg::Edit(task),
+                    crate::widgets::task::MsgOutput::Focus(task) => Msg::Focus(task),
                 });
 
         let inbox =
             crate::inbox::Model::builder()
                 .launch(())
                 .forward(sender.input_sender(), |output| match output {
+                    crate::widgets::task::MsgOutput::Apply(ids, change) => Msg::Apply(ids, change),
                     crate::widgets::task::MsgOutput::Complete(task) => Msg::Complete(task),
                     crate::widgets::task::MsgOutput::Edit(task) => Msg::Edit(task),
+                    crate::widgets::task::MsgOutput::Focus(task) => Msg::Focus(task),
                 });
 
+        let palette = crate::palette::Model::builder()
+            .transient_for(&root)
+            .launch(())
+            .forward(sender.input_sender(), std::convert::identity);
+
+        let preferences = crate::preferences::Model::builder()
+            .transient_for(&root)
+            .launch(globals::preferences::get())
+            .forward(sender.input_sender(), Msg::Preferences);
+
+        let standup = crate::standup::Model::builder()
+            .transient_for(&root)
+            .launch(())
+            .detach();
+
         let projects = crate::widgets::tags::Model::builder()
             .launch(crate::widgets::tags::Type::Projects)
             .forward(sender.input_sender(), |output| match output {
+                crate::widgets::tags::MsgOutput::Apply(ids, change) => Msg::Apply(ids, change),
                 crate::widgets::tags::MsgOutput::Complete(task) => Msg::Complete(task),
                 crate::widgets::tags::MsgOutput::Edit(task) => Msg::Edit(task),
+                crate::widgets::tags::MsgOutput::Focus(task) => Msg::Focus(task),
             });
 
         let search =
             crate::search::Model::builder()
                 .launch(())
                 .forward(sender.input_sender(), |output| match output {
+                    crate::widgets::task::MsgOutput::Apply(ids, change) => Msg::Apply(ids, change),
                     crate::widgets::task::MsgOutput::Complete(task) => Msg::Complete(task),
                     crate::widgets::task::MsgOutput::Edit(task) => Msg::Edit(task),
+                    crate::widgets::task::MsgOutput::Focus(task) => Msg::Focus(task),
                 });
 
+        let statistics = crate::statistics::Model::builder().launch(()).detach();
+
+        let toast = crate::toast::Model::builder()
+            .launch(())
+            .forward(sender.input_sender(), std::convert::identity);
+
         let tags = crate::widgets::tags::Model::builder()
             .launch(crate::widgets::tags::Type::Hashtags)
             .forward(sender.input_sender(), |output| match output {
+                crate::widgets::tags::MsgOutput::Apply(ids, change) => Msg::Apply(ids, change),
                 crate::widgets::tags::MsgOutput::Complete(task) => Msg::Complete(task),
                 crate::widgets::tags::MsgOutput::Edit(task) => Msg::Edit(task),
+                crate::widgets::tags::MsgOutput::Focus(task) => Msg::Focus(task),
             });
 
-        let builder = gtk::Builder::from_resource("/txt/todo/effitask/shortcuts.ui");
+        let builder = gtk::Builder::from_string(&crate::shortcuts::ui(&crate::shortcuts::all()));
         let shortcuts = builder.object("shortcuts").unwrap();
 
         let watcher = {
@@ -440,34 +1163,75 @@ impl relm4::Component for Model {
 
         let mut model = Self {
             watcher,
+            add,
             agenda,
             config: init,
             contexts,
+            counts: std::collections::HashMap::new(),
+            css: gtk::CssProvider::new(),
+            css_monitor: Self::watch_style(&sender),
             done,
             edit,
             flag,
+            focused: None,
             inbox,
             logger,
+            notifier: crate::notifications::Desktop::new(
+                relm4::main_application(),
+                sender.input_sender(),
+            ),
+            pages: Page::order(),
+            palette,
+            preferences,
             projects,
+            retry: None,
+            scheduler: crate::tasks::reminder::Scheduler::new(),
             search,
             shortcuts,
+            standup,
+            statistics,
             tags,
+            toast,
+            undo: None,
+            user_css: gtk::CssProvider::new(),
         };
 
         model.watch();
 
         let widgets = view_output!();
 
+        let display = gtk::gdk::Display::default().unwrap();
+        gtk::style_context_add_provider_for_display(&display, &model.css, 0);
+        gtk::style_context_add_provider_for_display(
+            &display,
+            &model.user_css,
+            gtk::STYLE_PROVIDER_PRIORITY_USER,
+        );
+
+        model.user_css.connect_parsing_error(|_, section, err| {
+            log::warn!("User stylesheet error at {}: {err}", section.to_str());
+        });
+
+        model.apply_theme();
         model.load_style();
-        model.add_tab_widgets(&widgets.notebook);
-        model.update_tasks(&widgets);
+        model.load_user_style();
+        model.add_pages(&widgets.notebook);
+        model.update_pages();
+        model.update_tasks();
         model.search.widget().set_visible(false);
+        model.restore_session(&root, &widgets);
+        model.notify();
+
+        {
+            let sender = sender.clone();
 
-        Self::check_button_set_markup(&widgets.defered_button);
-        Self::check_button_set_markup(&widgets.done_button);
-        Self::check_button_set_markup(&widgets.hidden_button);
+            gtk::glib::timeout_add_seconds_local(60, move || {
+                sender.input(Msg::Notify);
+                gtk::glib::ControlFlow::Continue
+            });
+        }
 
-        Self::shortcuts(&root, sender);
+        Self::shortcuts(&root, &widgets.notebook, sender);
 
         relm4::ComponentParts { model, widgets }
     }
@@ -477,30 +1241,76 @@ impl relm4::Component for Model {
         widgets: &mut Self::Widgets,
         msg: Self::Input,
         _: relm4::ComponentSender<Self>,
-        _: &Self::Root,
+        root: &Self::Root,
     ) {
         match msg {
             Msg::Add(task) => self.add(widgets, &task),
             Msg::Adding => widgets.add_popover.popup(),
+            Msg::Apply(ids, change) => self.apply(&ids, &change),
             Msg::AskRefresh => widgets.ask.set_visible(true),
             Msg::Cancel => widgets.ask.set_visible(false),
-            Msg::Complete(task) => self.complete(widgets, &task),
+            Msg::Complete(task) => self.complete(&task),
             Msg::EditCancel => self.edit.widget().set_visible(false),
-            Msg::EditDone(task) => self.save(widgets, &task),
+            Msg::EditDone(task) => self.save(&task),
             Msg::Edit(task) => self.edit(&task),
             Msg::Find => {
                 widgets.search.grab_focus();
             }
+            Msg::Focus(task) => self.focused = Some(*task),
             Msg::Help => self.shortcuts.present(),
+            Msg::Notification(action, id) => self.notification(root, action, &id),
+            Msg::Notify => self.notify(),
+            Msg::Page(page) => self.set_page(widgets, page),
+            Msg::PagesReordered => self.reorder_pages(widgets),
+            Msg::Palette => self
+                .palette
+                .emit(crate::palette::MsgInput::Show(self.commands())),
+            Msg::Preferences(preferences) => self.set_preferences(preferences),
+            Msg::Query(query) => widgets.search.set_text(&query),
             Msg::Refresh => {
-                self.update_tasks(widgets);
+                self.update_tasks();
                 widgets.ask.set_visible(false);
                 log::info!("Tasks reloaded");
             }
+            Msg::Retry => {
+                if let Some(write) = self.retry.take() {
+                    self.commit(write);
+                }
+            }
+            Msg::SaveSearch(query) => Self::save_search(&query),
             Msg::Search(query) => self.search(widgets, &query),
+            Msg::Select(page, tag) => self.select(widgets, page, tag),
+            Msg::ShowLogs => self.logger.emit(crate::logger::Msg::ShowLogs),
+            Msg::ShowPreferences => self.preferences.widget().present(),
+            Msg::ShowStandup => self.standup.emit(crate::standup::MsgInput::Show),
+            Msg::ThemeChanged => self.load_style(),
+            Msg::Undo => self.undo(),
+            Msg::UserStyleChanged => {
+                self.load_user_style();
+                log::info!("User stylesheet reloaded");
+            }
         }
     }
 
+    fn shutdown(&mut self, widgets: &mut Self::Widgets, _: relm4::Sender<Self::Output>) {
+        if let Some(monitor) = &self.css_monitor {
+            monitor.cancel();
+        }
+
+        let page = self
+            .current_page(widgets)
+            .filter(|x| *x != Page::Search)
+            .unwrap_or_else(|| self.home_page());
+
+        crate::settings::set_all(
+            "session",
+            &[
+                ("paned", widgets.paned.position().to_string()),
+                ("page", page.name().to_string()),
+            ],
+        );
+    }
+
     view! {
         gtk::ApplicationWindow {
             set_title: NAME.into(),
@@ -511,71 +1321,34 @@ impl relm4::Component for Model {
 
                     pack_start = &gtk::Button {
                         set_icon_name: "view-refresh",
-                        set_tooltip_text: "Refresh".into(),
+                        set_tooltip_text: Some(&gettext("Refresh")),
 
                         connect_clicked => Msg::Refresh,
                     },
                     pack_start = &gtk::MenuButton {
                         set_icon_name: "list-add",
-                        set_tooltip_text: "Add".into(),
+                        set_tooltip_text: Some(&gettext("Add")),
                         #[wrap(Some)]
                         #[name = "add_popover"]
                         set_popover = &gtk::Popover {
-                            gtk::Box {
-                                set_orientation: gtk::Orientation::Vertical,
-
-                                gtk::Entry {
-                                    connect_activate[sender] => move |this| {
-                                        sender.input(Msg::Add(this.text().to_string()));
-                                        this.set_text("");
-                                    }
-                                },
-                                gtk::Label {
-                                    set_text: "Create a new task +project @context due:2042-01-01",
-                                },
-                            },
+                            set_child: Some(model.add.widget()),
                         },
                     },
-                    pack_start = &gtk::MenuButton {
+                    pack_start = &gtk::Button {
                         set_icon_name: "preferences-system",
-                        set_tooltip_text: "Preferences".into(),
-                        #[wrap(Some)]
-                        set_popover = &gtk::Popover {
-                            gtk::Box {
-                                set_orientation: gtk::Orientation::Vertical,
-                                #[name = "defered_button"]
-                                gtk::CheckButton {
-                                    #[wrap(Some)]
-                                    set_child = &gtk::Label::new(
-                                        Some("Display <b>defered</b> tasks"),
-                                    ),
-
-                                    connect_toggled => Msg::Refresh,
-                                },
-                                #[name = "done_button"]
-                                gtk::CheckButton {
-                                    #[wrap(Some)]
-                                    set_child = &gtk::Label::new(
-                                        Some("Display <b>done</b> tasks"),
-                                    ),
-
-                                    connect_toggled => Msg::Refresh,
-                                },
-                                #[name = "hidden_button"]
-                                gtk::CheckButton {
-                                    #[wrap(Some)]
-                                    set_child = &gtk::Label::new(
-                                        Some("Display <b>hidden</b> tasks"),
-                                    ),
-
-                                    connect_toggled => Msg::Refresh,
-                                },
-                            },
-                        },
+                        set_tooltip_text: Some(&gettext("Preferences")),
+
+                        connect_clicked => Msg::ShowPreferences,
+                    },
+                    pack_start = &gtk::Button {
+                        set_icon_name: "x-office-document",
+                        set_tooltip_text: Some(&gettext("Standup report")),
+
+                        connect_clicked => Msg::ShowStandup,
                     },
                     pack_start = &gtk::Button {
                         set_icon_name: "help-about",
-                        set_tooltip_text: "Help".into(),
+                        set_tooltip_text: Some(&gettext("Help")),
 
                         connect_clicked => Msg::Help,
                     },
@@ -583,6 +1356,9 @@ impl relm4::Component for Model {
                     pack_end = model.logger.widget(),
                     #[name = "search"]
                     pack_end = &gtk::SearchEntry {
+                        connect_activate[sender] => move |this| {
+                            sender.input(Msg::SaveSearch(this.text().to_string()));
+                        },
                         connect_search_changed[sender] => move |this| {
                             sender.input(Msg::Search(this.text().to_string()));
                         },
@@ -596,46 +1372,87 @@ impl relm4::Component for Model {
 
                     gtk::Label {
                         set_hexpand: true,
-                        set_text: "Tasks have been modified from an external program, would you like to reload them?",
+                        set_text: &gettext("Tasks have been modified from an external program, would you like to reload them?"),
                     },
                     gtk::Button {
                         add_css_class: "suggested-action",
-                        set_label: "Yes",
+                        set_label: &gettext("Yes"),
                         connect_clicked => Msg::Refresh,
                     },
                     gtk::Button {
-                        set_label: "No",
+                        set_label: &gettext("No"),
                         connect_clicked => Msg::Cancel,
                     },
                 },
-                gtk::Paned {
-                    set_hexpand: true,
-                    set_vexpand: true,
-                    set_orientation: gtk::Orientation::Horizontal,
-                    set_wide_handle: true,
+                gtk::Overlay {
+                    add_overlay: model.toast.widget(),
 
                     #[wrap(Some)]
-                    #[name = "notebook"]
-                    set_start_child = &gtk::Notebook {
-                        set_tab_pos: gtk::PositionType::Left,
-
-                        append_page: (model.inbox.widget(), None::<&gtk::Label>),
-                        append_page: (model.projects.widget(), None::<&gtk::Label>),
-                        append_page: (model.contexts.widget(), None::<&gtk::Label>),
-                        append_page: (model.tags.widget(), None::<&gtk::Label>),
-                        append_page: (model.agenda.widget(), None::<&gtk::Label>),
-                        append_page: (model.flag.widget(), None::<&gtk::Label>),
-                        append_page: (model.done.widget(), None::<&gtk::Label>),
-                        append_page: (model.search.widget(), None::<&gtk::Label>),
+                    #[name = "paned"]
+                    set_child = &gtk::Paned {
+                        set_hexpand: true,
+                        set_vexpand: true,
+                        set_orientation: gtk::Orientation::Horizontal,
+                        set_wide_handle: true,
+
+                        #[wrap(Some)]
+                        #[name = "notebook"]
+                        set_start_child = &gtk::Notebook {
+                            set_tab_pos: gtk::PositionType::Left,
+
+                            connect_page_reordered[sender] => move |_, _, _| {
+                                sender.input(Msg::PagesReordered);
+                            },
+                        },
+                        #[wrap(Some)]
+                        set_end_child = model.edit.widget(),
                     },
-                    #[wrap(Some)]
-                    set_end_child = model.edit.widget(),
                 },
             },
-            connect_close_request => move |_| {
+            connect_close_request => move |window| {
+                Self::save_geometry(window);
                 relm4::main_application().quit();
                 gtk::glib::Propagation::Stop
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::application::*;
+
+    #[gtk::test]
+    fn tab_accessibility() {
+        let (tab, count) = Model::tab_widget(Page::Inbox);
+        let count = count.unwrap();
+
+        assert!(gtk::test_accessible_has_role(
+            &tab,
+            gtk::AccessibleRole::Group
+        ));
+        assert_eq!(
+            crate::widgets::check_property(&tab, gtk::AccessibleProperty::Label, "Inbox"),
+            Ok(())
+        );
+
+        Model::set_count(&count, 3, "{count} tasks in inbox", true);
+        assert_eq!(
+            crate::widgets::check_property(
+                &tab,
+                gtk::AccessibleProperty::Description,
+                "3 tasks in inbox"
+            ),
+            Ok(())
+        );
+
+        Model::set_count(&count, 3, "{count} tasks in inbox", false);
+        assert_eq!(
+            crate::widgets::check_property(&tab, gtk::AccessibleProperty::Description, ""),
+            Ok(())
+        );
+
+        let (_, count) = Model::tab_widget(Page::Projects);
+        assert!(count.is_none());
+    }
+}
@@ -1,12 +1,155 @@
-#[derive(Clone, Default)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Theme {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub const ALL: [Self; 3] = [Self::System, Self::Light, Self::Dark];
+
+    pub fn label(&self) -> String {
+        use crate::i18n::gettext;
+
+        match self {
+            Self::System => gettext("System"),
+            Self::Light => gettext("Light"),
+            Self::Dark => gettext("Dark"),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+}
+
+impl std::str::FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|x| x.name() == s)
+            .ok_or_else(|| format!("Invalid theme '{s}'"))
+    }
+}
+
+impl std::fmt::Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Preferences {
-    pub defered: bool,
-    pub done: bool,
-    pub hidden: bool,
+    pub agenda_month: u32,
+    pub agenda_week: u32,
+    pub blocker: String,
+    pub date_format: String,
+    pub hidden_pages: Vec<super::Page>,
+    pub log_level: log::LevelFilter,
+    pub notification_times: Vec<chrono::NaiveTime>,
+    pub notifications: bool,
+    pub priority: u8,
+    pub startup_page: Option<super::Page>,
+    pub tab_counts: bool,
+    pub theme: Theme,
+    pub week_start: chrono::Weekday,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            agenda_month: 28,
+            agenda_week: 7,
+            blocker: "blocked".to_string(),
+            date_format: "%x".to_string(),
+            hidden_pages: Vec::new(),
+            log_level: log::LevelFilter::Info,
+            notification_times: vec![chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap()],
+            notifications: true,
+            priority: 26,
+            startup_page: None,
+            tab_counts: false,
+            theme: Theme::System,
+            week_start: chrono::Weekday::Mon,
+        }
+    }
 }
 
 impl Preferences {
+    const GROUP: &str = "preferences";
+
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn load() -> Self {
+        use crate::settings::get;
+
+        let default = Self::default();
+
+        Self {
+            agenda_month: get(Self::GROUP, "agenda_month").unwrap_or(default.agenda_month),
+            agenda_week: get(Self::GROUP, "agenda_week").unwrap_or(default.agenda_week),
+            blocker: get(Self::GROUP, "blocker").unwrap_or(default.blocker),
+            date_format: get(Self::GROUP, "date_format").unwrap_or(default.date_format),
+            hidden_pages: get::<String>(Self::GROUP, "hidden_pages")
+                .map(|x| x.split(',').filter_map(|x| x.trim().parse().ok()).collect())
+                .unwrap_or(default.hidden_pages),
+            log_level: get(Self::GROUP, "log_level").unwrap_or(default.log_level),
+            notification_times: get::<String>(Self::GROUP, "notification_times")
+                .and_then(|x| crate::date::parse_times(&x))
+                .unwrap_or(default.notification_times),
+            notifications: get(Self::GROUP, "notifications").unwrap_or(default.notifications),
+            priority: get(Self::GROUP, "priority").unwrap_or(default.priority),
+            startup_page: get::<String>(Self::GROUP, "startup_page")
+                .map(|x| x.parse().ok())
+                .unwrap_or(default.startup_page),
+            tab_counts: get(Self::GROUP, "tab_counts").unwrap_or(default.tab_counts),
+            theme: get(Self::GROUP, "theme").unwrap_or(default.theme),
+            week_start: get(Self::GROUP, "week_start")
+                .unwrap_or_else(|| crate::date::first_weekday(&crate::date::locale())),
+        }
+    }
+
+    pub fn save(&self) {
+        crate::settings::set_all(
+            Self::GROUP,
+            &[
+                ("agenda_month", self.agenda_month.to_string()),
+                ("agenda_week", self.agenda_week.to_string()),
+                ("blocker", self.blocker.clone()),
+                ("date_format", self.date_format.clone()),
+                (
+                    "hidden_pages",
+                    self.hidden_pages
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ),
+                ("log_level", self.log_level.to_string().to_lowercase()),
+                (
+                    "notification_times",
+                    crate::date::format_times(&self.notification_times),
+                ),
+                ("notifications", self.notifications.to_string()),
+                ("priority", self.priority.to_string()),
+                (
+                    "startup_page",
+                    self.startup_page
+                        .map_or_else(|| "last".to_string(), |x| x.to_string()),
+                ),
+                ("tab_counts", self.tab_counts.to_string()),
+                ("theme", self.theme.to_string()),
+                ("week_start", self.week_start.to_string()),
+            ],
+        );
+    }
 }
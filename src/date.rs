@@ -1,3 +1,24 @@
+use chrono::Datelike as _;
+
+use crate::i18n::gettext;
+
+static SATURDAY: &[&str] = &[
+    "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY",
+];
+
+static SUNDAY: &[&str] = &[
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CO", "DM", "DO", "ET", "GT", "GU", "HK",
+    "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX", "MZ",
+    "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW", "UM",
+    "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+static LOCALIZED: &[&str] = &["%x", "%c", "%a", "%A", "%b", "%B", "%h", "%p"];
+
+static DATE_TAGS: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
+    regex::Regex::new(r"(?P<space>^|\s)(?P<key>due|t):(?P<value>\S+)").unwrap()
+});
+
 pub fn today() -> chrono::NaiveDate {
     chrono::Local::now().date_naive()
 }
@@ -9,3 +30,360 @@ pub fn from_glib(value: gtk::glib::DateTime) -> chrono::NaiveDate {
 
     chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()
 }
+
+pub fn to_glib(date: chrono::NaiveDate) -> Option<gtk::glib::DateTime> {
+    gtk::glib::DateTime::from_local(
+        date.year(),
+        date.month() as i32,
+        date.day() as i32,
+        0,
+        0,
+        0.,
+    )
+    .ok()
+}
+
+pub fn locale() -> String {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .filter_map(|x| std::env::var(x).ok())
+        .find(|x| !x.is_empty())
+        .unwrap_or_default()
+}
+
+pub fn first_weekday(locale: &str) -> chrono::Weekday {
+    let territory = locale
+        .split(['.', '@'])
+        .next()
+        .and_then(|x| x.split_once('_'))
+        .map_or("", |(_, territory)| territory);
+
+    if SATURDAY.contains(&territory) {
+        chrono::Weekday::Sat
+    } else if SUNDAY.contains(&territory) {
+        chrono::Weekday::Sun
+    } else {
+        chrono::Weekday::Mon
+    }
+}
+
+pub fn parse(text: &str) -> Option<chrono::NaiveDate> {
+    let preferences = crate::application::preferences();
+
+    parse_from(text, today(), preferences.week_start)
+        .or_else(|| parse_with(text, &preferences.date_format))
+}
+
+pub fn parse_with(text: &str, format: &str) -> Option<chrono::NaiveDate> {
+    if LOCALIZED.iter().any(|x| format.contains(x)) {
+        return None;
+    }
+
+    chrono::NaiveDate::parse_from_str(text.trim(), format).ok()
+}
+
+pub fn parse_from(
+    text: &str,
+    today: chrono::NaiveDate,
+    week_start: chrono::Weekday,
+) -> Option<chrono::NaiveDate> {
+    let text = text.trim().to_lowercase();
+
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(&text, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    if let Some(offset) = text.strip_prefix('+') {
+        return offset_from(today, 1, offset);
+    } else if let Some(offset) = text.strip_prefix('-') {
+        return offset_from(today, -1, offset);
+    }
+
+    let words = text
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>();
+
+    match words.as_slice() {
+        ["today" | "tod"] => Some(today),
+        ["tomorrow" | "tom"] => today.succ_opt(),
+        ["yesterday"] => today.pred_opt(),
+        ["end", "of", period] | ["eo", period] => end_of(today, period, week_start),
+        ["eow"] => end_of(today, "week", week_start),
+        ["eom"] => end_of(today, "month", week_start),
+        ["eoy"] => end_of(today, "year", week_start),
+        ["next", period @ ("week" | "month" | "year")] => add(today, 1, period),
+        ["next", weekday] => {
+            let start = end_of_week(today, week_start).succ_opt()?;
+
+            Some(next_weekday(start, weekday.parse().ok()?, 0))
+        }
+        [weekday] if weekday.parse::<chrono::Weekday>().is_ok() => {
+            Some(next_weekday(today, weekday.parse().ok()?, 7))
+        }
+        ["in", n, period] => add(today, n.parse().ok()?, period),
+        _ => None,
+    }
+}
+
+fn next_weekday(from: chrono::NaiveDate, weekday: chrono::Weekday, same: i64) -> chrono::NaiveDate {
+    let days = (weekday.num_days_from_monday() + 7 - from.weekday().num_days_from_monday()) % 7;
+
+    from + chrono::Duration::days(if days == 0 { same } else { days.into() })
+}
+
+fn offset_from(date: chrono::NaiveDate, sign: i64, offset: &str) -> Option<chrono::NaiveDate> {
+    let split = offset.find(|c: char| !c.is_ascii_digit())?;
+    let (n, period) = offset.split_at(split);
+
+    add(date, sign * n.parse::<i64>().ok()?, period)
+}
+
+fn add(date: chrono::NaiveDate, n: i64, period: &str) -> Option<chrono::NaiveDate> {
+    let months = |n: i64| -> Option<chrono::NaiveDate> {
+        let months = chrono::Months::new(n.unsigned_abs().try_into().ok()?);
+
+        if n < 0 {
+            date.checked_sub_months(months)
+        } else {
+            date.checked_add_months(months)
+        }
+    };
+
+    match period {
+        "d" | "day" | "days" => date.checked_add_signed(chrono::Duration::days(n)),
+        "w" | "week" | "weeks" => date.checked_add_signed(chrono::Duration::weeks(n)),
+        "m" | "month" | "months" => months(n),
+        "y" | "year" | "years" => months(n.checked_mul(12)?),
+        _ => None,
+    }
+}
+
+fn end_of(
+    date: chrono::NaiveDate,
+    period: &str,
+    week_start: chrono::Weekday,
+) -> Option<chrono::NaiveDate> {
+    match period {
+        "w" | "week" => Some(end_of_week(date, week_start)),
+        "m" | "month" => {
+            let first = date.with_day(1)?;
+
+            first.checked_add_months(chrono::Months::new(1))?.pred_opt()
+        }
+        "y" | "year" => chrono::NaiveDate::from_ymd_opt(date.year(), 12, 31),
+        _ => None,
+    }
+}
+
+pub fn end_of_week(date: chrono::NaiveDate, start: chrono::Weekday) -> chrono::NaiveDate {
+    let days = (start.num_days_from_monday() + 13 - date.weekday().num_days_from_monday()) % 7;
+
+    date + chrono::Duration::days(days.into())
+}
+
+pub fn format(date: chrono::NaiveDate) -> String {
+    format_with(date, &crate::application::preferences().date_format)
+        .unwrap_or_else(|| date.format("%Y-%m-%d").to_string())
+}
+
+pub fn format_with(date: chrono::NaiveDate, format: &str) -> Option<String> {
+    to_glib(date)?.format(format).ok().map(|x| x.to_string())
+}
+
+pub fn alias(date: chrono::NaiveDate, today: chrono::NaiveDate) -> String {
+    if date == today {
+        gettext("today")
+    } else if Some(date) == today.pred_opt() {
+        gettext("yesterday")
+    } else if Some(date) == today.succ_opt() {
+        gettext("tomorrow")
+    } else {
+        format(date)
+    }
+}
+
+pub fn format_editable(date: chrono::NaiveDate) -> String {
+    let format = crate::application::preferences().date_format;
+
+    format_with(date, &format)
+        .filter(|text| parse_with(text, &format) == Some(date))
+        .unwrap_or_else(|| date.format("%Y-%m-%d").to_string())
+}
+
+pub fn normalize(text: &str) -> String {
+    DATE_TAGS
+        .replace_all(text, |caps: &regex::Captures<'_>| {
+            match parse(&caps["value"]) {
+                Some(date) => format!(
+                    "{}{}:{}",
+                    &caps["space"],
+                    &caps["key"],
+                    date.format("%Y-%m-%d")
+                ),
+                None => caps[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+pub fn parse_times(text: &str) -> Option<Vec<chrono::NaiveTime>> {
+    let mut times = text
+        .split(',')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| chrono::NaiveTime::parse_from_str(x, "%H:%M").ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    times.sort();
+    times.dedup();
+
+    Some(times)
+}
+
+pub fn format_times(times: &[chrono::NaiveTime]) -> String {
+    times
+        .iter()
+        .map(|x| x.format("%H:%M").to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::date::*;
+
+    #[test]
+    fn alias() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+        assert_eq!(super::alias(today, today), "today");
+        assert_eq!(super::alias(today.pred_opt().unwrap(), today), "yesterday");
+        assert_eq!(super::alias(today.succ_opt().unwrap(), today), "tomorrow");
+    }
+
+    #[test]
+    fn parse() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d);
+
+        let tests = [
+            ("2042-01-01", date(2042, 1, 1)),
+            ("today", date(2026, 10, 15)),
+            ("Tomorrow", date(2026, 10, 16)),
+            ("yesterday", date(2026, 10, 14)),
+            ("fri", date(2026, 10, 16)),
+            ("thursday", date(2026, 10, 22)),
+            ("mon", date(2026, 10, 19)),
+            ("next fri", date(2026, 10, 23)),
+            ("next thu", date(2026, 10, 22)),
+            ("next mon", date(2026, 10, 19)),
+            ("next-week", date(2026, 10, 22)),
+            ("in 3 weeks", date(2026, 11, 5)),
+            ("in 1 month", date(2026, 11, 15)),
+            ("+2d", date(2026, 10, 17)),
+            ("-1w", date(2026, 10, 8)),
+            ("+1y", date(2027, 10, 15)),
+            ("end of month", date(2026, 10, 31)),
+            ("eow", date(2026, 10, 18)),
+            ("end of year", date(2026, 12, 31)),
+            ("soon", None),
+            ("in x days", None),
+            ("+2", None),
+        ];
+
+        for (text, expected) in tests {
+            assert_eq!(
+                parse_from(text, today, chrono::Weekday::Mon),
+                expected,
+                "{text}"
+            );
+        }
+
+        let sunday = chrono::NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        assert_eq!(
+            parse_from("next fri", sunday, chrono::Weekday::Mon),
+            date(2026, 10, 23)
+        );
+        assert_eq!(
+            parse_from("next fri", sunday, chrono::Weekday::Sun),
+            date(2026, 10, 30)
+        );
+        assert_eq!(
+            parse_from("eow", today, chrono::Weekday::Sun),
+            date(2026, 10, 17)
+        );
+    }
+
+    #[test]
+    fn end_of_week() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let date = |d| chrono::NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+
+        assert_eq!(
+            crate::date::end_of_week(today, chrono::Weekday::Mon),
+            date(18)
+        );
+        assert_eq!(
+            crate::date::end_of_week(today, chrono::Weekday::Sun),
+            date(17)
+        );
+        assert_eq!(
+            crate::date::end_of_week(today, chrono::Weekday::Fri),
+            date(15)
+        );
+        assert_eq!(
+            crate::date::end_of_week(today, chrono::Weekday::Thu),
+            date(21)
+        );
+    }
+
+    #[test]
+    fn format() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+        assert_eq!(format_with(date, "%d/%m/%Y").as_deref(), Some("15/10/2026"));
+        assert_eq!(
+            format_with(date, "%A"),
+            format_with(date + chrono::Duration::weeks(1), "%A")
+        );
+        assert_ne!(
+            format_with(date, "%A"),
+            format_with(date.succ_opt().unwrap(), "%A")
+        );
+        assert_eq!(format_with(date, "%Q"), None);
+
+        assert_eq!(parse_with("15.10.2026", "%d.%m.%Y"), Some(date));
+        assert_eq!(parse_with("10/15/26", "%x"), None);
+        assert_eq!(parse_with("Thu 15 Oct", "%a %d %b"), None);
+    }
+
+    #[test]
+    fn first_weekday() {
+        use chrono::Weekday;
+
+        assert_eq!(crate::date::first_weekday("fr_FR.UTF-8"), Weekday::Mon);
+        assert_eq!(crate::date::first_weekday("de_DE@euro"), Weekday::Mon);
+        assert_eq!(crate::date::first_weekday("en_US.UTF-8"), Weekday::Sun);
+        assert_eq!(crate::date::first_weekday("ar_EG"), Weekday::Sat);
+        assert_eq!(crate::date::first_weekday("C"), Weekday::Mon);
+        assert_eq!(crate::date::first_weekday(""), Weekday::Mon);
+    }
+
+    #[test]
+    fn times() {
+        let time = |h, m| chrono::NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        assert_eq!(
+            super::parse_times("17:30, 9:00,,09:00 "),
+            Some(vec![time(9, 0), time(17, 30)])
+        );
+        assert_eq!(super::parse_times(""), Some(Vec::new()));
+        assert_eq!(super::parse_times("9h"), None);
+        assert_eq!(
+            super::format_times(&[time(9, 0), time(17, 30)]),
+            "09:00, 17:30"
+        );
+    }
+}
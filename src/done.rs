@@ -1,29 +1,133 @@
 use gtk::prelude::*;
 
+use crate::i18n::{gettext, gettext_noop, ngettext};
+
 #[derive(Debug)]
 pub enum Msg {
+    Copy,
+    Day(Option<chrono::NaiveDate>),
+    Export,
+    From(Option<chrono::NaiveDate>),
+    Save(std::path::PathBuf),
+    To(Option<chrono::NaiveDate>),
     Update,
 }
 
 pub struct Model {
+    chooser: Option<gtk::FileChooserNative>,
+    day: std::rc::Rc<std::cell::Cell<Option<chrono::NaiveDate>>>,
+    from: Option<chrono::NaiveDate>,
+    from_calendar: relm4::Controller<crate::widgets::calendar::Model>,
+    heatmap: relm4::Controller<crate::widgets::heatmap::Model>,
+    sort: relm4::Controller<crate::widgets::sort::Model>,
     tasks: relm4::Controller<crate::widgets::tasks::Model>,
+    to: Option<chrono::NaiveDate>,
+    to_calendar: relm4::Controller<crate::widgets::calendar::Model>,
+    visibility: relm4::Controller<crate::widgets::visibility::Model>,
 }
 
 impl Model {
-    fn tasks() -> Vec<crate::tasks::Task> {
+    fn tasks(
+        visibility: &crate::tasks::Visibility,
+        day: Option<chrono::NaiveDate>,
+    ) -> Vec<crate::tasks::Task> {
+        let today = crate::date::today();
         let list = crate::application::tasks();
-        let preferences = crate::application::preferences();
 
         list.tasks
             .iter()
-            .filter(|x| x.finished && (preferences.hidden || !x.hidden))
+            .filter(|x| x.finished && visibility.matches(x, today))
+            .filter(|x| day.is_none_or(|day| x.finish_date == Some(day)))
             .cloned()
             .collect()
     }
+
+    fn range(&self) -> (chrono::NaiveDate, chrono::NaiveDate) {
+        let to = self.to.unwrap_or_else(crate::date::today);
+        let from = self.from.unwrap_or(to - chrono::Duration::days(6));
+
+        (from, to)
+    }
+
+    fn markdown(&self) -> String {
+        let list = crate::application::tasks();
+
+        crate::tasks::logbook::markdown(&list.tasks, self.range(), crate::date::today())
+    }
+
+    fn export(&mut self, root: &gtk::Box, sender: relm4::ComponentSender<Self>) {
+        let (from, to) = self.range();
+        let parent = root.root().and_downcast::<gtk::Window>();
+        let chooser = gtk::FileChooserNative::new(
+            Some(&gettext("Export done tasks")),
+            parent.as_ref(),
+            gtk::FileChooserAction::Save,
+            Some(&gettext("Save")),
+            Some(&gettext("Cancel")),
+        );
+        chooser.set_current_name(&format!("done-{from}-{to}.md"));
+
+        chooser.connect_response(move |chooser, response| {
+            if response == gtk::ResponseType::Accept
+                && let Some(path) = chooser.file().and_then(|x| x.path())
+            {
+                sender.input(Msg::Save(path));
+            }
+        });
+
+        chooser.show();
+        self.chooser = Some(chooser);
+    }
+
+    fn update_streaks(&self, widgets: &ModelWidgets) {
+        use relm4::ComponentController as _;
+
+        let list = crate::application::tasks();
+        let completions = crate::tasks::statistics::completions(&list.tasks);
+        let (current, longest) =
+            crate::tasks::statistics::streaks(&completions, crate::date::today());
+
+        widgets.streaks.set_text(&format!(
+            "{} · {}",
+            ngettext(
+                "Current streak: {days} day",
+                "Current streak: {days} days",
+                current as u32
+            )
+            .replace("{days}", &current.to_string()),
+            ngettext(
+                "Longest streak: {days} day",
+                "Longest streak: {days} days",
+                longest as u32
+            )
+            .replace("{days}", &longest.to_string()),
+        ));
+
+        self.heatmap
+            .emit(crate::widgets::heatmap::MsgInput::Set(completions));
+    }
+
+    fn select(&self, widgets: &ModelWidgets, day: Option<chrono::NaiveDate>) {
+        use relm4::ComponentController as _;
+
+        self.day.set(day);
+        self.heatmap
+            .emit(crate::widgets::heatmap::MsgInput::Select(day));
+        self.tasks.emit(crate::widgets::tasks::MsgInput::NeedUpdate);
+
+        widgets.day.set_visible(day.is_some());
+
+        if let Some(day) = day {
+            widgets.day_label.set_text(
+                &gettext("Completed on {date}").replace("{date}", &crate::date::format(day)),
+            );
+        }
+    }
 }
 
 #[relm4::component(pub)]
-impl relm4::SimpleComponent for Model {
+impl relm4::Component for Model {
+    type CommandOutput = ();
     type Init = ();
     type Input = Msg;
     type Output = crate::widgets::task::MsgOutput;
@@ -36,30 +140,177 @@ impl relm4::SimpleComponent for Model {
         use relm4::Component as _;
         use relm4::ComponentController as _;
 
+        let day = std::rc::Rc::new(std::cell::Cell::new(None));
+
         let tasks = crate::widgets::tasks::Model::builder()
-            .launch(crate::Filter::from(Model::tasks))
+            .launch(crate::Filter::from(gtk::glib::clone!(
+                #[strong]
+                day,
+                move |visibility| Model::tasks(visibility, day.get())
+            )))
             .forward(sender.output_sender(), std::convert::identity);
 
-        let model = Self { tasks };
+        let from_calendar = crate::widgets::calendar::Model::builder()
+            .launch(gettext_noop("From"))
+            .forward(sender.input_sender(), |output| match output {
+                crate::widgets::calendar::MsgOutput::Updated(date) => Msg::From(date),
+            });
+
+        let to_calendar = crate::widgets::calendar::Model::builder()
+            .launch(gettext_noop("To"))
+            .forward(sender.input_sender(), |output| match output {
+                crate::widgets::calendar::MsgOutput::Updated(date) => Msg::To(date),
+            });
+
+        let heatmap = crate::widgets::heatmap::Model::builder()
+            .launch(())
+            .forward(sender.input_sender(), |output| match output {
+                crate::widgets::heatmap::MsgOutput::Clicked(date) => Msg::Day(Some(date)),
+            });
+
+        let sort = crate::widgets::sort::Model::builder()
+            .launch((
+                "done",
+                crate::tasks::Sort {
+                    group: crate::tasks::sort::Group::Day,
+                    ..Default::default()
+                },
+            ))
+            .forward(tasks.sender(), crate::widgets::tasks::MsgInput::Sort);
+
+        let visibility = crate::widgets::visibility::Model::builder()
+            .launch((
+                "done",
+                crate::tasks::Visibility {
+                    defered: true,
+                    done: true,
+                    hidden: false,
+                },
+            ))
+            .forward(tasks.sender(), crate::widgets::tasks::MsgInput::Visibility);
+
+        let model = Self {
+            chooser: None,
+            day,
+            from: None,
+            from_calendar,
+            heatmap,
+            sort,
+            tasks,
+            to: None,
+            to_calendar,
+            visibility,
+        };
+
+        let (from, to) = model.range();
+        model
+            .from_calendar
+            .emit(crate::widgets::calendar::MsgInput::Set(Some(from)));
+        model
+            .to_calendar
+            .emit(crate::widgets::calendar::MsgInput::Set(Some(to)));
 
         let widgets = view_output!();
 
         relm4::ComponentParts { model, widgets }
     }
 
-    fn update(&mut self, msg: Self::Input, _: relm4::ComponentSender<Self>) {
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
         use relm4::ComponentController as _;
 
         match msg {
-            Msg::Update => self
-                .tasks
-                .sender()
-                .emit(crate::widgets::tasks::MsgInput::NeedUpdate),
+            Msg::Copy => {
+                root.clipboard().set_text(&self.markdown());
+                log::info!("Done tasks copied to clipboard");
+            }
+            Msg::Day(day) => {
+                let day = day.filter(|x| self.day.get() != Some(*x));
+                self.select(widgets, day);
+            }
+            Msg::Export => self.export(root, sender),
+            Msg::From(date) => self.from = date,
+            Msg::Save(path) => match std::fs::write(&path, self.markdown()) {
+                Ok(()) => log::info!("Done tasks exported to {path:?}"),
+                Err(err) => log::error!("Unable to export done tasks: {err}"),
+            },
+            Msg::To(date) => self.to = date,
+            Msg::Update => {
+                self.update_streaks(widgets);
+                self.tasks
+                    .sender()
+                    .emit(crate::widgets::tasks::MsgInput::NeedUpdate);
+            }
         }
     }
 
     view! {
         gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            append: model.heatmap.widget(),
+            #[name = "streaks"]
+            gtk::Label {
+                add_css_class: "dim-label",
+            },
+            gtk::Box {
+                set_orientation: gtk::Orientation::Horizontal,
+
+                #[name = "day"]
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 5,
+                    set_visible: false,
+
+                    #[name = "day_label"]
+                    gtk::Label {
+                    },
+                    gtk::Button {
+                        set_icon_name: "edit-clear",
+                        set_tooltip_text: Some(&gettext("Show all")),
+
+                        connect_clicked => Msg::Day(None),
+                    },
+                },
+                gtk::Box {
+                    set_halign: gtk::Align::End,
+                    set_hexpand: true,
+                    set_orientation: gtk::Orientation::Horizontal,
+
+                    gtk::MenuButton {
+                        set_icon_name: "document-send",
+                        set_tooltip_text: Some(&gettext("Report")),
+
+                        #[wrap(Some)]
+                        set_popover = &gtk::Popover {
+                            gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 5,
+
+                                append: model.from_calendar.widget(),
+                                append: model.to_calendar.widget(),
+                                gtk::Button {
+                                    set_label: &gettext("Copy as Markdown"),
+
+                                    connect_clicked => Msg::Copy,
+                                },
+                                gtk::Button {
+                                    set_label: &gettext("Export range…"),
+
+                                    connect_clicked => Msg::Export,
+                                },
+                            },
+                        },
+                    },
+                    append: model.visibility.widget(),
+                    append: model.sort.widget(),
+                },
+            },
             append: model.tasks.widget(),
         }
     }
@@ -1,4 +1,6 @@
 use gtk::prelude::*;
+
+use crate::i18n::{gettext, gettext_noop};
 use relm4::ComponentController as _;
 
 #[derive(Debug)]
@@ -63,12 +65,12 @@ impl relm4::Component for Model {
         sender: relm4::ComponentSender<Self>,
     ) -> relm4::ComponentParts<Self> {
         let created = crate::widgets::calendar::Model::builder()
-            .launch("Created")
+            .launch(gettext_noop("Created"))
             .detach();
         created.widget().set_sensitive(false);
 
         let due = crate::widgets::calendar::Model::builder()
-            .launch("Due")
+            .launch(gettext_noop("Due"))
             .forward(sender.input_sender(), |output| match output {
                 crate::widgets::calendar::MsgOutput::Updated(date) => {
                     MsgInput::UpdateDate(DateType::Due, date)
@@ -84,7 +86,7 @@ impl relm4::Component for Model {
             });
 
         let finish = crate::widgets::calendar::Model::builder()
-            .launch("Completed")
+            .launch(gettext_noop("Completed"))
             .forward(sender.input_sender(), |output| match output {
                 crate::widgets::calendar::MsgOutput::Updated(date) => {
                     MsgInput::UpdateDate(DateType::Finish, date)
@@ -108,7 +110,7 @@ impl relm4::Component for Model {
             });
 
         let threshold = crate::widgets::calendar::Model::builder()
-            .launch("Defer until")
+            .launch(gettext_noop("Defer until"))
             .forward(sender.input_sender(), |output| match output {
                 crate::widgets::calendar::MsgOutput::Updated(date) => {
                     MsgInput::UpdateDate(DateType::Threshold, date)
@@ -193,14 +195,14 @@ impl relm4::Component for Model {
                 set_spacing: 10,
 
                 gtk::Frame {
-                    set_label: Some("Subject"),
+                    set_label: Some(&gettext("Subject")),
                     #[name = "subject"]
                     gtk::Entry {
                         connect_activate => MsgInput::Ok,
                     },
                 },
                 gtk::Frame {
-                    set_label: Some("Priority"),
+                    set_label: Some(&gettext("Priority")),
                     gtk::Box {
                         set_orientation: gtk::Orientation::Horizontal,
 
@@ -211,16 +213,16 @@ impl relm4::Component for Model {
                             set_hexpand: true,
                             set_halign: gtk::Align::Center,
                             set_icon_name: "emblem-favorite",
-                            set_tooltip_text: Some("Flag"),
+                            set_tooltip_text: Some(&gettext("Flag")),
                         },
                     },
                 },
                 gtk::Frame {
-                    set_label: Some("Repeat"),
+                    set_label: Some(&gettext("Repeat")),
                     set_child: Some(model.recurrence.widget()),
                 },
                 gtk::Frame {
-                    set_label: Some("Date"),
+                    set_label: Some(&gettext("Date")),
                     gtk::Box {
                         set_spacing: 10,
                         set_orientation: gtk::Orientation::Vertical,
@@ -232,12 +234,12 @@ impl relm4::Component for Model {
                     },
                 },
                 gtk::Frame {
-                    set_label: Some("Keywords"),
+                    set_label: Some(&gettext("Keywords")),
 
                     set_child: Some(model.keywords.widget()),
                 },
                 gtk::Frame {
-                    set_label: Some("Note"),
+                    set_label: Some(&gettext("Note")),
 
                     #[name = "note"]
                     gtk::TextView {
@@ -253,12 +255,12 @@ impl relm4::Component for Model {
                 gtk::ActionBar {
                     pack_start = &gtk::Button {
                         add_css_class: "suggested-action",
-                        set_label: "Ok",
+                        set_label: &gettext("Ok"),
 
                         connect_clicked => MsgInput::Ok,
                     },
                     pack_start = &gtk::Button {
-                        set_label: "Cancel",
+                        set_label: &gettext("Cancel"),
 
                         connect_clicked[sender] => move |_| {
                             sender.output(MsgOutput::Cancel).ok();
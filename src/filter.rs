@@ -1,14 +1,18 @@
-pub struct Filter(Box<dyn Fn() -> Vec<crate::tasks::Task>>);
+type Function = dyn Fn(&crate::tasks::Visibility) -> Vec<crate::tasks::Task>;
+
+pub struct Filter(Box<Function>);
 
 impl Filter {
-    pub fn from<F: Fn() -> Vec<crate::tasks::Task> + 'static>(f: F) -> Self {
+    pub fn from<F: Fn(&crate::tasks::Visibility) -> Vec<crate::tasks::Task> + 'static>(
+        f: F,
+    ) -> Self {
         Self(Box::new(f))
     }
 }
 
 impl Default for Filter {
     fn default() -> Self {
-        Self(Box::new(Vec::new))
+        Self(Box::new(|_| Vec::new()))
     }
 }
 
@@ -19,7 +23,7 @@ impl From<()> for Filter {
 }
 
 impl std::ops::Deref for Filter {
-    type Target = Box<dyn Fn() -> Vec<crate::tasks::Task>>;
+    type Target = Box<Function>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -6,25 +6,19 @@ pub enum Msg {
 }
 
 pub struct Model {
+    sort: relm4::Controller<crate::widgets::sort::Model>,
     tasks: relm4::Controller<crate::widgets::tasks::Model>,
+    visibility: relm4::Controller<crate::widgets::visibility::Model>,
 }
 
 impl Model {
-    fn tasks() -> Vec<crate::tasks::Task> {
+    fn tasks(visibility: &crate::tasks::Visibility) -> Vec<crate::tasks::Task> {
         let today = crate::date::today();
         let list = crate::application::tasks();
-        let preferences = crate::application::preferences();
 
         list.tasks
             .iter()
-            .filter(|x| {
-                x.flagged
-                    && (preferences.done || !x.finished)
-                    && (preferences.hidden || !x.hidden)
-                    && (preferences.defered
-                        || x.threshold_date.is_none()
-                        || x.threshold_date.unwrap() <= today)
-            })
+            .filter(|x| x.flagged && visibility.matches(x, today))
             .cloned()
             .collect()
     }
@@ -48,7 +42,19 @@ impl relm4::SimpleComponent for Model {
             .launch(crate::Filter::from(Model::tasks))
             .forward(sender.output_sender(), std::convert::identity);
 
-        let model = Self { tasks };
+        let sort = crate::widgets::sort::Model::builder()
+            .launch(("flag", crate::tasks::Sort::default()))
+            .forward(tasks.sender(), crate::widgets::tasks::MsgInput::Sort);
+
+        let visibility = crate::widgets::visibility::Model::builder()
+            .launch(("flag", crate::tasks::Visibility::default()))
+            .forward(tasks.sender(), crate::widgets::tasks::MsgInput::Visibility);
+
+        let model = Self {
+            sort,
+            tasks,
+            visibility,
+        };
 
         let widgets = view_output!();
 
@@ -65,6 +71,15 @@ impl relm4::SimpleComponent for Model {
 
     view! {
         gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            gtk::Box {
+                set_halign: gtk::Align::End,
+                set_orientation: gtk::Orientation::Horizontal,
+
+                append: model.visibility.widget(),
+                append: model.sort.widget(),
+            },
             append: model.tasks.widget(),
         }
     }
@@ -0,0 +1,25 @@
+pub use gettextrs::{gettext, ngettext, pgettext};
+
+const LOCALEDIR: &str = match option_env!("LOCALEDIR") {
+    Some(dir) => dir,
+    None => "/usr/share/locale",
+};
+
+pub fn init() {
+    use gettextrs::LocaleCategory;
+
+    let domain = crate::application::NAME;
+
+    gettextrs::setlocale(LocaleCategory::LcAll, "");
+
+    if let Err(err) = gettextrs::bindtextdomain(domain, LOCALEDIR) {
+        log::warn!("Unable to bind text domain to {LOCALEDIR}: {err}");
+    }
+
+    gettextrs::bind_textdomain_codeset(domain, "UTF-8").ok();
+    gettextrs::textdomain(domain).ok();
+}
+
+pub const fn gettext_noop(msgid: &'static str) -> &'static str {
+    msgid
+}
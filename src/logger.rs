@@ -1,16 +1,178 @@
 use gtk::prelude::*;
 
-type ChannelData = (log::Level, String);
-type Sender = std::sync::mpsc::Sender<ChannelData>;
-type Receiver = std::sync::mpsc::Receiver<ChannelData>;
+use crate::i18n::gettext;
+
+type Sender = std::sync::mpsc::Sender<Entry>;
+type Receiver = std::sync::mpsc::Receiver<Entry>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub time: String,
+    pub level: log::Level,
+    pub target: String,
+    pub text: String,
+}
+
+impl Entry {
+    fn new(record: &log::Record<'_>) -> Self {
+        let time = gtk::glib::DateTime::now_local()
+            .and_then(|x| x.format("%F %T"))
+            .map(|x| x.to_string())
+            .unwrap_or_default();
+
+        Self {
+            time,
+            level: record.level(),
+            target: record.target().to_string(),
+            text: record.args().to_string(),
+        }
+    }
+
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.splitn(4, ' ');
+        let date = parts.next()?;
+        let time = parts.next()?;
+        let level = parts.next()?.parse().ok()?;
+        let (target, text) = parts.next()?.split_once(": ")?;
+
+        Some(Self {
+            time: format!("{date} {time}"),
+            level,
+            target: target.to_string(),
+            text: text.to_string(),
+        })
+    }
+
+    pub fn is_relevant(&self) -> bool {
+        Self::is_own(&self.target) && self.level <= log::Level::Info
+    }
+
+    fn is_own(target: &str) -> bool {
+        let name = crate::application::NAME;
+
+        target == name || target.starts_with(&format!("{name}::"))
+    }
+}
+
+impl std::fmt::Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}: {}",
+            self.time,
+            self.level,
+            self.target,
+            self.text.replace('\n', " ")
+        )
+    }
+}
+
+struct File {
+    file: Option<std::fs::File>,
+    path: std::path::PathBuf,
+    size: u64,
+}
+
+impl File {
+    const KEEP: usize = 3;
+    const MAX_SIZE: u64 = 1024 * 1024;
+
+    fn open(path: std::path::PathBuf) -> Self {
+        if let Some(parent) = path.parent()
+            && let Err(err) = std::fs::create_dir_all(parent)
+        {
+            eprintln!("Unable to create {parent:?}: {err}");
+        }
+
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .inspect_err(|err| eprintln!("Unable to open {path:?}: {err}"))
+            .ok();
+        let size = file
+            .as_ref()
+            .and_then(|x| x.metadata().ok())
+            .map_or(0, |x| x.len());
+
+        Self { file, path, size }
+    }
+
+    fn rotated(path: &std::path::Path, n: usize) -> std::path::PathBuf {
+        let mut path = path.as_os_str().to_owned();
+        path.push(format!(".{n}"));
+
+        path.into()
+    }
+
+    fn rotate(&mut self) {
+        self.file = None;
+
+        for n in (1..Self::KEEP).rev() {
+            std::fs::rename(
+                Self::rotated(&self.path, n),
+                Self::rotated(&self.path, n + 1),
+            )
+            .ok();
+        }
+
+        std::fs::rename(&self.path, Self::rotated(&self.path, 1)).ok();
+
+        *self = Self::open(self.path.clone());
+    }
+
+    fn write(&mut self, line: &str) {
+        use std::io::Write as _;
+
+        if self.size + line.len() as u64 >= Self::MAX_SIZE {
+            self.rotate();
+        }
+
+        if let Some(file) = &mut self.file
+            && writeln!(file, "{line}").is_ok()
+        {
+            self.size += line.len() as u64 + 1;
+        }
+    }
+}
+
+pub fn path() -> std::path::PathBuf {
+    std::env::var_os("XDG_STATE_HOME")
+        .filter(|x| !x.is_empty())
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| gtk::glib::home_dir().join(".local").join("state"))
+        .join(crate::application::NAME)
+        .join(format!("{}.log", crate::application::NAME))
+}
+
+pub fn filter(spec: &str) -> Option<log::LevelFilter> {
+    let directives = spec.split(',').map(str::trim).collect::<Vec<_>>();
+
+    directives
+        .iter()
+        .find_map(|x| match x.split_once('=') {
+            Some((target, level)) if target == crate::application::NAME => level.parse().ok(),
+            _ => None,
+        })
+        .or_else(|| directives.iter().find_map(|x| x.parse().ok()))
+}
+
+pub fn level(preference: log::LevelFilter) -> log::LevelFilter {
+    std::env::var("RUST_LOG")
+        .ok()
+        .and_then(|x| filter(&x))
+        .unwrap_or(preference)
+}
 
 pub struct Log {
+    file: std::sync::Mutex<File>,
     tx: std::sync::Mutex<Sender>,
 }
 
 impl Log {
     pub fn new(tx: Sender) -> Self {
         Self {
+            file: std::sync::Mutex::new(File::open(path())),
             tx: std::sync::Mutex::new(tx),
         }
     }
@@ -18,16 +180,34 @@ impl Log {
 
 impl log::Log for Log {
     fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
-        metadata.target() == crate::application::NAME && metadata.level() >= log::Level::Info
+        Entry::is_own(metadata.target()) || metadata.level() <= log::Level::Warn
     }
 
     fn log(&self, record: &log::Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let entry = Entry::new(record);
+
+        if let Ok(mut file) = self.file.lock() {
+            file.write(&entry.to_string());
+        }
+
         if let Ok(tx) = self.tx.lock() {
-            tx.send((record.level(), format!("{}", record.args()))).ok();
+            tx.send(entry).ok();
         }
     }
 
-    fn flush(&self) {}
+    fn flush(&self) {
+        use std::io::Write as _;
+
+        if let Ok(mut file) = self.file.lock()
+            && let Some(file) = &mut file.file
+        {
+            file.flush().ok();
+        }
+    }
 }
 
 thread_local!(
@@ -37,13 +217,15 @@ thread_local!(
 
 #[derive(Debug)]
 pub enum Msg {
-    Add(ChannelData),
+    Add(Entry),
     Clear,
     Read(gtk::ListBoxRow),
+    ShowLogs,
 }
 
 pub struct Model {
-    messages: Vec<ChannelData>,
+    logs: relm4::Controller<crate::logs::Model>,
+    messages: Vec<(log::Level, String)>,
 }
 
 impl Model {
@@ -59,6 +241,12 @@ impl Model {
         gtk::glib::ControlFlow::Continue
     }
 
+    fn history() -> Vec<Entry> {
+        std::fs::read_to_string(path())
+            .map(|x| x.lines().filter_map(Entry::parse).collect())
+            .unwrap_or_default()
+    }
+
     fn add_message(&mut self, widgets: &ModelWidgets, level: log::Level, text: &str) {
         let class = level.to_string();
 
@@ -86,13 +274,20 @@ impl relm4::Component for Model {
         root: Self::Root,
         sender: relm4::ComponentSender<Self>,
     ) -> relm4::ComponentParts<Self> {
+        use relm4::Component as _;
+        use relm4::ComponentController as _;
+
+        let logs = crate::logs::Model::builder().launch(()).detach();
+        logs.emit(crate::logs::MsgInput::Add(Self::history()));
+
         let (tx, rx) = std::sync::mpsc::channel();
         let log = Log::new(tx);
 
-        log::set_max_level(log::LevelFilter::Info);
+        log::set_max_level(level(crate::application::preferences().log_level));
         log::set_boxed_logger(Box::new(log)).unwrap_or_default();
 
         let model = Self {
+            logs,
             messages: Vec::new(),
         };
 
@@ -112,9 +307,15 @@ impl relm4::Component for Model {
         _: relm4::ComponentSender<Self>,
         _: &Self::Root,
     ) {
+        use relm4::ComponentController as _;
+
         match msg {
-            Msg::Add((level, text)) => {
-                self.add_message(widgets, level, &text);
+            Msg::Add(entry) => {
+                if entry.is_relevant() {
+                    self.add_message(widgets, entry.level, &entry.text);
+                }
+
+                self.logs.emit(crate::logs::MsgInput::Add(vec![entry]));
             }
             Msg::Clear => {
                 use relm4::RelmRemoveAllExt as _;
@@ -126,11 +327,17 @@ impl relm4::Component for Model {
                 widgets.list_box.remove(&row);
                 self.messages.remove(row.index() as usize);
             }
+            Msg::ShowLogs => {
+                widgets.button.popdown();
+                self.logs.emit(crate::logs::MsgInput::Show);
+            }
         }
 
         let count = self.messages.len();
         widgets.button.set_visible(count > 0);
-        widgets.button.set_label(&format!("Notifications {count}"));
+        widgets
+            .button
+            .set_label(&gettext("Notifications {count}").replace("{count}", &count.to_string()));
 
         if let Some(priority) = self.higher_priority() {
             widgets
@@ -165,12 +372,87 @@ impl relm4::Component for Model {
                         }
                     },
                     gtk::Button {
-                        set_label: "Clear all",
+                        set_label: &gettext("Clear all"),
                         set_icon_name: "list-remove-all",
+                        set_tooltip_text: Some(&gettext("Clear all")),
                         connect_clicked => Msg::Clear,
                     },
+                    gtk::Button {
+                        set_label: &gettext("Show all logs"),
+                        connect_clicked => Msg::ShowLogs,
+                    },
                 },
             },
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::logger::*;
+
+    #[test]
+    fn entry() {
+        let entry = Entry {
+            time: "2026-10-19 09:15:00".to_string(),
+            level: log::Level::Debug,
+            target: "effitask::application".to_string(),
+            text: "watching todo.txt\nfor changes".to_string(),
+        };
+
+        let line = entry.to_string();
+        assert_eq!(
+            line,
+            "2026-10-19 09:15:00 DEBUG effitask::application: watching todo.txt for changes"
+        );
+
+        let parsed = Entry::parse(&line).unwrap();
+        assert_eq!(parsed.level, log::Level::Debug);
+        assert_eq!(parsed.target, "effitask::application");
+        assert!(!parsed.is_relevant());
+
+        assert!(Entry::parse("garbage").is_none());
+        assert!(
+            Entry::parse("2026-10-19 09:15:00 INFO effitask: Tasks reloaded")
+                .unwrap()
+                .is_relevant()
+        );
+        assert!(
+            !Entry::parse("2026-10-19 09:15:00 WARN relm4: slow")
+                .unwrap()
+                .is_relevant()
+        );
+    }
+
+    #[test]
+    fn filter() {
+        assert_eq!(super::filter("debug"), Some(log::LevelFilter::Debug));
+        assert_eq!(
+            super::filter("warn, effitask=trace"),
+            Some(log::LevelFilter::Trace)
+        );
+        assert_eq!(super::filter("relm4=debug"), None);
+        assert_eq!(super::filter(""), None);
+    }
+
+    #[test]
+    fn rotate() {
+        let dir = std::env::temp_dir().join(format!("effitask-log-{}", std::process::id()));
+        let path = dir.join("effitask.log");
+
+        let mut file = File::open(path.clone());
+        let line = "x".repeat(1000);
+
+        for _ in 0..(File::MAX_SIZE as usize / 1000) * 4 {
+            file.write(&line);
+        }
+
+        assert!(path.exists());
+        assert!(File::rotated(&path, 1).exists());
+        assert!(File::rotated(&path, File::KEEP).exists());
+        assert!(!File::rotated(&path, File::KEEP + 1).exists());
+        assert!(std::fs::metadata(&path).unwrap().len() < File::MAX_SIZE);
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,176 @@
+use gtk::prelude::*;
+
+use crate::i18n::gettext;
+use crate::logger::Entry;
+
+static LEVELS: [log::Level; 5] = [
+    log::Level::Error,
+    log::Level::Warn,
+    log::Level::Info,
+    log::Level::Debug,
+    log::Level::Trace,
+];
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Add(Vec<Entry>),
+    Filter,
+    Show,
+}
+
+pub struct Model {
+    entries: std::collections::VecDeque<(Entry, gtk::Label)>,
+}
+
+impl Model {
+    const MAX_ENTRIES: usize = 5000;
+
+    fn levels() -> gtk::DropDown {
+        let labels = [
+            gettext("Errors"),
+            gettext("Warnings"),
+            gettext("Information"),
+            gettext("Debug"),
+            gettext("Trace"),
+        ];
+        let dropdown =
+            gtk::DropDown::from_strings(&labels.iter().map(String::as_str).collect::<Vec<_>>());
+        dropdown.set_selected(3);
+
+        dropdown
+    }
+
+    fn matches(widgets: &ModelWidgets, entry: &Entry) -> bool {
+        let level = LEVELS
+            .get(widgets.level.selected() as usize)
+            .copied()
+            .unwrap_or(log::Level::Trace);
+        let query = widgets.search.text().to_lowercase();
+
+        entry.level <= level
+            && (query.is_empty() || entry.to_string().to_lowercase().contains(&query))
+    }
+
+    fn add(&mut self, widgets: &ModelWidgets, entry: Entry) {
+        let label = gtk::Label::new(Some(&entry.to_string()));
+        label.add_css_class(&entry.level.to_string().to_lowercase());
+        label.set_selectable(true);
+        label.set_wrap(true);
+        label.set_xalign(0.);
+
+        widgets.list_box.append(&label);
+
+        if let Some(row) = label.parent() {
+            row.set_visible(Self::matches(widgets, &entry));
+        }
+
+        self.entries.push_back((entry, label));
+
+        if self.entries.len() > Self::MAX_ENTRIES
+            && let Some((_, label)) = self.entries.pop_front()
+            && let Some(row) = label.parent()
+        {
+            widgets.list_box.remove(&row);
+        }
+    }
+
+    fn filter(&self, widgets: &ModelWidgets) {
+        for (entry, label) in &self.entries {
+            let visible = Self::matches(widgets, entry);
+
+            if let Some(row) = label.parent() {
+                row.set_visible(visible);
+            }
+        }
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = ();
+    type Input = MsgInput;
+    type Output = ();
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let model = Self {
+            entries: std::collections::VecDeque::new(),
+        };
+
+        let widgets = view_output!();
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        _: relm4::ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        match msg {
+            MsgInput::Add(entries) => {
+                for entry in entries {
+                    self.add(widgets, entry);
+                }
+            }
+            MsgInput::Filter => self.filter(widgets),
+            MsgInput::Show => {
+                root.present();
+                widgets.search.grab_focus();
+            }
+        }
+    }
+
+    view! {
+        gtk::Window {
+            set_default_size: (800, 500),
+            set_hide_on_close: true,
+            set_title: Some(&gettext("Logs")),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_bottom: 10,
+                set_margin_end: 10,
+                set_margin_start: 10,
+                set_margin_top: 10,
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 5,
+
+                    #[name = "search"]
+                    gtk::SearchEntry {
+                        set_hexpand: true,
+                        set_placeholder_text: Some(&gettext("Search logs")),
+
+                        connect_search_changed => MsgInput::Filter,
+                    },
+                    #[name = "level"]
+                    append = &Self::levels() {
+                        set_tooltip_text: Some(&gettext("Level")),
+
+                        connect_selected_notify => MsgInput::Filter,
+                    },
+                },
+                gtk::ScrolledWindow {
+                    set_vexpand: true,
+                    set_policy: (gtk::PolicyType::Never, gtk::PolicyType::Automatic),
+
+                    #[name = "list_box"]
+                    gtk::ListBox {
+                        add_css_class: "monospace",
+                        set_selection_mode: gtk::SelectionMode::None,
+                        update_property: &[gtk::accessible::Property::Label(&gettext("Logs"))],
+                    },
+                },
+            },
+        }
+    }
+}
@@ -1,5 +1,6 @@
 #![warn(warnings)]
 
+mod add;
 mod agenda;
 mod application;
 mod date;
@@ -7,10 +8,20 @@ mod done;
 mod edit;
 mod filter;
 mod flag;
+mod i18n;
 mod inbox;
 mod logger;
+mod logs;
+mod notifications;
+mod palette;
+mod preferences;
 mod search;
+mod settings;
+mod shortcuts;
+mod standup;
+mod statistics;
 mod tasks;
+mod toast;
 mod widgets;
 
 use filter::Filter;
@@ -19,6 +30,7 @@ fn main() {
     human_panic::setup_panic!();
 
     envir::init();
+    i18n::init();
 
     if std::env::args().nth(1).as_deref() == Some("usage") {
         usage(&std::env::args().next().unwrap());
@@ -26,6 +38,12 @@ fn main() {
         std::process::exit(0);
     }
 
+    if std::env::args().nth(1).as_deref() == Some("standup") {
+        let code = standup(std::env::args().nth(2));
+
+        std::process::exit(code);
+    }
+
     let config = todo_txt::Config::from_env();
 
     let app = relm4::RelmApp::new("txt.todo.effitask").with_args(Vec::new());
@@ -39,6 +57,39 @@ fn usage(program: &str) {
 
     println!("    {}", path.file_name().unwrap().to_str().unwrap());
     println!("      Launch focus graphical interface");
+    println!(
+        "    {} standup [FILE]",
+        path.file_name().unwrap().to_str().unwrap()
+    );
+    println!("      Print the standup report, or write it to FILE");
+}
+
+fn standup(output: Option<String>) -> i32 {
+    let config = todo_txt::Config::from_env();
+
+    application::load_preferences();
+
+    let list = tasks::List::from_files(&config.todo_file, &config.done_file);
+    let report = tasks::standup::Standup::new(
+        &list.tasks,
+        date::today(),
+        &application::preferences().blocker,
+    )
+    .render(&tasks::standup::template());
+
+    match output {
+        Some(path) => match std::fs::write(&path, report) {
+            Ok(()) => 0,
+            Err(err) => {
+                eprintln!("Unable to write {path}: {err}");
+                1
+            }
+        },
+        None => {
+            print!("{report}");
+            0
+        }
+    }
 }
 
 fn initialize_resources() {
@@ -0,0 +1,182 @@
+use gtk::prelude::*;
+
+use crate::i18n::gettext;
+use crate::tasks::reminder::{Kind, Reminder};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    Complete,
+    Open,
+    SnoozeHour,
+    SnoozeDay,
+}
+
+impl Action {
+    const ALL: [Self; 4] = [
+        Self::Complete,
+        Self::Open,
+        Self::SnoozeHour,
+        Self::SnoozeDay,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Complete => "complete-task",
+            Self::Open => "open-task",
+            Self::SnoozeHour => "snooze-hour",
+            Self::SnoozeDay => "snooze-day",
+        }
+    }
+}
+
+pub struct Desktop {
+    application: gtk::Application,
+}
+
+impl Desktop {
+    pub fn new(
+        application: gtk::Application,
+        sender: &relm4::Sender<crate::application::Msg>,
+    ) -> Self {
+        for action in Action::ALL {
+            let simple_action =
+                gtk::gio::SimpleAction::new(action.name(), Some(gtk::glib::VariantTy::STRING));
+            let sender = sender.clone();
+
+            simple_action.connect_activate(move |_, parameter| {
+                if let Some(id) = parameter.and_then(|x| x.get::<String>()) {
+                    sender.emit(crate::application::Msg::Notification(action, id));
+                }
+            });
+
+            application.add_action(&simple_action);
+        }
+
+        Self { application }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Content {
+    title: String,
+    body: String,
+    priority: gtk::gio::NotificationPriority,
+    target: String,
+    default_action: String,
+    buttons: Vec<(String, String)>,
+}
+
+impl Content {
+    fn new(reminder: &Reminder) -> Self {
+        let detailed = |action: Action| format!("app.{}", action.name());
+
+        Self {
+            title: reminder.kind.title(),
+            body: reminder.task.subject.clone(),
+            priority: match reminder.kind {
+                Kind::Overdue => gtk::gio::NotificationPriority::High,
+                Kind::Due | Kind::Threshold => gtk::gio::NotificationPriority::Normal,
+            },
+            target: reminder.id(),
+            default_action: detailed(Action::Open),
+            buttons: vec![
+                (gettext("Complete"), detailed(Action::Complete)),
+                (gettext("Snooze 1 hour"), detailed(Action::SnoozeHour)),
+                (gettext("Snooze 1 day"), detailed(Action::SnoozeDay)),
+            ],
+        }
+    }
+
+    fn notification(&self) -> gtk::gio::Notification {
+        let target = self.target.to_variant();
+
+        let notification = gtk::gio::Notification::new(&self.title);
+        notification.set_body(Some(&self.body));
+        notification.set_priority(self.priority);
+        notification.set_default_action_and_target_value(&self.default_action, Some(&target));
+
+        for (label, action) in &self.buttons {
+            notification.add_button_with_target_value(label, action, Some(&target));
+        }
+
+        notification
+    }
+}
+
+impl crate::tasks::reminder::Notifier for Desktop {
+    fn send(&mut self, reminder: &Reminder) {
+        let content = Content::new(reminder);
+
+        log::debug!("Notify '{}'", reminder.task.subject);
+        self.application
+            .send_notification(Some(&content.target), &content.notification());
+    }
+
+    fn withdraw(&mut self, id: &str) {
+        self.application.withdraw_notification(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notifications::*;
+
+    #[gtk::test]
+    fn desktop() {
+        let task = "Call Mom due:2026-10-10"
+            .parse::<crate::tasks::Task>()
+            .unwrap();
+        let reminder = Reminder {
+            kind: Kind::Overdue,
+            task: task.clone(),
+        };
+        let id = crate::tasks::reminder::id(&task);
+
+        let content = Content::new(&reminder);
+        assert_eq!(
+            content,
+            Content {
+                title: "Overdue task".to_string(),
+                body: "Call Mom".to_string(),
+                priority: gtk::gio::NotificationPriority::High,
+                target: id.clone(),
+                default_action: "app.open-task".to_string(),
+                buttons: vec![
+                    ("Complete".to_string(), "app.complete-task".to_string()),
+                    ("Snooze 1 hour".to_string(), "app.snooze-hour".to_string()),
+                    ("Snooze 1 day".to_string(), "app.snooze-day".to_string()),
+                ],
+            }
+        );
+
+        let application = gtk::Application::new(None::<&str>, Default::default());
+        let (sender, receiver) = relm4::channel();
+        let _desktop = Desktop::new(application.clone(), &sender);
+
+        let actions = std::iter::once(&content.default_action)
+            .chain(content.buttons.iter().map(|(_, action)| action));
+
+        for (detailed, expected) in actions.zip([
+            Action::Open,
+            Action::Complete,
+            Action::SnoozeHour,
+            Action::SnoozeDay,
+        ]) {
+            let name = detailed.strip_prefix("app.").unwrap();
+            let action = application.lookup_action(name).unwrap();
+            assert_eq!(
+                action.parameter_type().as_deref(),
+                Some(gtk::glib::VariantTy::STRING)
+            );
+
+            action.activate(Some(&content.target.to_variant()));
+
+            match receiver.recv_sync() {
+                Some(crate::application::Msg::Notification(action, target)) => {
+                    assert_eq!((action, target), (expected, id.clone()));
+                }
+                _ => panic!("{detailed} was not routed to the application"),
+            }
+        }
+    }
+}
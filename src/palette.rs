@@ -0,0 +1,275 @@
+use gtk::prelude::*;
+
+use crate::i18n::gettext;
+
+#[derive(Clone, Debug)]
+pub struct Command {
+    pub category: String,
+    pub title: String,
+    pub accel: Option<String>,
+    pub msg: crate::application::Msg,
+}
+
+impl Command {
+    pub fn new(category: &str, title: &str, msg: crate::application::Msg) -> Self {
+        Self {
+            category: category.to_string(),
+            title: title.to_string(),
+            accel: None,
+            msg,
+        }
+    }
+
+    pub fn with_accel(mut self, accel: Option<&String>) -> Self {
+        self.accel = accel.cloned();
+        self
+    }
+}
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Activate(Option<i32>),
+    Filter(String),
+    Move(i32),
+    Show(Vec<Command>),
+}
+
+pub struct Model {
+    commands: Vec<Command>,
+    matches: Vec<usize>,
+}
+
+pub fn score(query: &str, text: &str) -> Option<i32> {
+    let text = text.to_lowercase().chars().collect::<Vec<_>>();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous = None;
+
+    for c in query.to_lowercase().chars().filter(|x| !x.is_whitespace()) {
+        let found = position + text[position..].iter().position(|x| *x == c)?;
+
+        score += 1;
+
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 8;
+        }
+
+        match previous {
+            Some(previous) if previous + 1 == found => score += 5,
+            Some(_) => score -= (found - position).min(10) as i32,
+            None => (),
+        }
+
+        previous = Some(found);
+        position = found + 1;
+    }
+
+    Some(score)
+}
+
+impl Model {
+    fn filter(&mut self, widgets: &ModelWidgets, query: &str) {
+        use relm4::RelmRemoveAllExt as _;
+
+        let mut matches = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(n, command)| {
+                score(query, &format!("{} {}", command.category, command.title))
+                    .map(|score| (score, n))
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+        self.matches = matches.into_iter().map(|(_, n)| n).collect();
+
+        widgets.list.remove_all();
+
+        for n in &self.matches {
+            widgets.list.append(&Self::row(&self.commands[*n]));
+        }
+
+        widgets
+            .list
+            .select_row(widgets.list.row_at_index(0).as_ref());
+    }
+
+    fn row(command: &Command) -> gtk::Box {
+        let row = gtk::Box::new(gtk::Orientation::Horizontal, 10);
+
+        let category = gtk::Label::new(Some(&command.category));
+        category.add_css_class("dim-label");
+        category.set_width_chars(10);
+        category.set_xalign(0.);
+        row.append(&category);
+
+        let title = gtk::Label::new(Some(&command.title));
+        title.set_ellipsize(gtk::pango::EllipsizeMode::End);
+        title.set_hexpand(true);
+        title.set_xalign(0.);
+        row.append(&title);
+
+        if let Some(accel) = &command.accel {
+            row.append(&gtk::ShortcutLabel::new(accel));
+        }
+
+        row
+    }
+
+    fn move_selection(&self, widgets: &ModelWidgets, delta: i32) {
+        let Some(last) = (self.matches.len() as i32).checked_sub(1) else {
+            return;
+        };
+
+        let current = widgets.list.selected_row().map_or(0, |x| x.index());
+        let next = (current + delta).clamp(0, last);
+
+        if let Some(row) = widgets.list.row_at_index(next) {
+            widgets.list.select_row(Some(&row));
+            row.grab_focus();
+            widgets.entry.grab_focus_without_selecting();
+        }
+    }
+
+    fn activate(
+        &self,
+        widgets: &ModelWidgets,
+        root: &gtk::Window,
+        sender: relm4::ComponentSender<Self>,
+        index: Option<i32>,
+    ) {
+        let Some(index) = index.or_else(|| widgets.list.selected_row().map(|x| x.index())) else {
+            return;
+        };
+
+        let Some(command) = usize::try_from(index)
+            .ok()
+            .and_then(|x| self.matches.get(x))
+            .map(|x| &self.commands[*x])
+        else {
+            return;
+        };
+
+        root.set_visible(false);
+        sender.output(command.msg.clone()).ok();
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = ();
+    type Input = MsgInput;
+    type Output = crate::application::Msg;
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let model = Self {
+            commands: Vec::new(),
+            matches: Vec::new(),
+        };
+
+        let widgets = view_output!();
+
+        let keys = gtk::EventControllerKey::new();
+        keys.connect_key_pressed(move |_, key, _, _| match key {
+            gtk::gdk::Key::Up => {
+                sender.input(MsgInput::Move(-1));
+                gtk::glib::Propagation::Stop
+            }
+            gtk::gdk::Key::Down => {
+                sender.input(MsgInput::Move(1));
+                gtk::glib::Propagation::Stop
+            }
+            _ => gtk::glib::Propagation::Proceed,
+        });
+        widgets.entry.add_controller(keys);
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        match msg {
+            MsgInput::Activate(index) => self.activate(widgets, root, sender, index),
+            MsgInput::Filter(query) => self.filter(widgets, &query),
+            MsgInput::Move(delta) => self.move_selection(widgets, delta),
+            MsgInput::Show(commands) => {
+                self.commands = commands;
+                widgets.entry.set_text("");
+                self.filter(widgets, "");
+                root.present();
+                widgets.entry.grab_focus();
+            }
+        }
+    }
+
+    view! {
+        gtk::Window {
+            set_default_size: (500, 400),
+            set_hide_on_close: true,
+            set_modal: true,
+            set_title: Some(&gettext("Command palette")),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_bottom: 10,
+                set_margin_end: 10,
+                set_margin_start: 10,
+                set_margin_top: 10,
+
+                #[name = "entry"]
+                gtk::SearchEntry {
+                    set_placeholder_text: Some(&gettext("Type a command, page, project or context")),
+
+                    connect_search_changed[sender] => move |this| {
+                        sender.input(MsgInput::Filter(this.text().to_string()));
+                    },
+                    connect_activate => MsgInput::Activate(None),
+                    connect_stop_search[root] => move |_| root.set_visible(false),
+                },
+                gtk::ScrolledWindow {
+                    set_policy: (gtk::PolicyType::Never, gtk::PolicyType::Automatic),
+                    set_vexpand: true,
+
+                    #[name = "list"]
+                    gtk::ListBox {
+                        set_selection_mode: gtk::SelectionMode::Browse,
+
+                        connect_row_activated[sender] => move |_, row| {
+                            sender.input(MsgInput::Activate(Some(row.index())));
+                        },
+                    },
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::palette::*;
+
+    #[test]
+    fn fuzzy() {
+        assert_eq!(score("", "Pages Inbox"), Some(0));
+        assert_eq!(score("xyz", "Pages Inbox"), None);
+        assert_eq!(score("ip", "Pages Inbox"), None);
+        assert!(score("inb", "Pages Inbox") > score("inb", "Task Find in bob"));
+        assert!(score("dt", "Task Due tomorrow") > score("dt", "Task Defer one month"));
+        assert_eq!(
+            score("WORK", "Projects +work"),
+            score("work", "Projects +Work")
+        );
+    }
+}
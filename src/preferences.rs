@@ -0,0 +1,428 @@
+use gtk::prelude::*;
+
+use crate::application::{Page, Preferences, Theme};
+use crate::i18n::gettext;
+
+static LOG_LEVELS: [log::LevelFilter; 5] = [
+    log::LevelFilter::Error,
+    log::LevelFilter::Warn,
+    log::LevelFilter::Info,
+    log::LevelFilter::Debug,
+    log::LevelFilter::Trace,
+];
+
+static WEEKDAYS: [chrono::Weekday; 7] = [
+    chrono::Weekday::Mon,
+    chrono::Weekday::Tue,
+    chrono::Weekday::Wed,
+    chrono::Weekday::Thu,
+    chrono::Weekday::Fri,
+    chrono::Weekday::Sat,
+    chrono::Weekday::Sun,
+];
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Apply,
+    Changed,
+    Timeout(u32),
+}
+
+pub struct Model {
+    generation: u32,
+    pages: Vec<(Page, gtk::CheckButton)>,
+    preferences: Preferences,
+}
+
+impl Model {
+    const DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+    fn weekdays(selected: chrono::Weekday) -> gtk::DropDown {
+        let monday = chrono::NaiveDate::from_isoywd_opt(2024, 1, chrono::Weekday::Mon).unwrap();
+        let labels = WEEKDAYS
+            .iter()
+            .map(|x| {
+                let date = monday + chrono::Duration::days(x.num_days_from_monday().into());
+
+                crate::date::format_with(date, "%A").unwrap_or_else(|| x.to_string())
+            })
+            .collect::<Vec<_>>();
+
+        let dropdown =
+            gtk::DropDown::from_strings(&labels.iter().map(String::as_str).collect::<Vec<_>>());
+        dropdown.set_selected(selected.num_days_from_monday());
+
+        dropdown
+    }
+
+    fn priorities(selected: u8) -> gtk::DropDown {
+        let labels = std::iter::once(gettext("None"))
+            .chain((b'A'..=b'Z').map(|x| (x as char).to_string()))
+            .collect::<Vec<_>>();
+
+        let dropdown =
+            gtk::DropDown::from_strings(&labels.iter().map(String::as_str).collect::<Vec<_>>());
+
+        if selected < 26 {
+            dropdown.set_selected(u32::from(selected) + 1);
+        }
+
+        dropdown
+    }
+
+    fn log_levels(selected: log::LevelFilter) -> gtk::DropDown {
+        let labels = [
+            gettext("Errors"),
+            gettext("Warnings"),
+            gettext("Information"),
+            gettext("Debug"),
+            gettext("Trace"),
+        ];
+        let dropdown =
+            gtk::DropDown::from_strings(&labels.iter().map(String::as_str).collect::<Vec<_>>());
+
+        if let Some(position) = LOG_LEVELS.iter().position(|x| *x == selected) {
+            dropdown.set_selected(position as u32);
+        }
+
+        dropdown
+    }
+
+    fn themes(selected: Theme) -> gtk::DropDown {
+        let labels = Theme::ALL.iter().map(Theme::label).collect::<Vec<_>>();
+        let dropdown =
+            gtk::DropDown::from_strings(&labels.iter().map(String::as_str).collect::<Vec<_>>());
+
+        if let Some(position) = Theme::ALL.iter().position(|x| *x == selected) {
+            dropdown.set_selected(position as u32);
+        }
+
+        dropdown
+    }
+
+    fn startup_pages(selected: Option<Page>) -> gtk::DropDown {
+        let labels = std::iter::once(gettext("Last used"))
+            .chain(Page::customizable().map(Page::label))
+            .collect::<Vec<_>>();
+        let dropdown =
+            gtk::DropDown::from_strings(&labels.iter().map(String::as_str).collect::<Vec<_>>());
+
+        if let Some(position) = Page::customizable().position(|x| Some(x) == selected) {
+            dropdown.set_selected(position as u32 + 1);
+        }
+
+        dropdown
+    }
+
+    fn page_buttons(
+        hidden: &[Page],
+        sender: &relm4::ComponentSender<Self>,
+    ) -> Vec<(Page, gtk::CheckButton)> {
+        Page::customizable()
+            .map(|page| {
+                let button = gtk::CheckButton::with_label(&page.label());
+                button.set_active(!hidden.contains(&page));
+
+                let sender = sender.clone();
+                button.connect_toggled(move |_| sender.input(MsgInput::Changed));
+
+                (page, button)
+            })
+            .collect()
+    }
+
+    fn format(&self, widgets: &ModelWidgets) -> Option<String> {
+        let format = widgets.date_format.text().to_string();
+
+        match crate::date::format_with(crate::date::today(), &format) {
+            Some(preview) if !format.trim().is_empty() => {
+                widgets.date_format.remove_css_class("error");
+                widgets.preview.set_text(&preview);
+
+                Some(format)
+            }
+            _ => {
+                widgets.date_format.add_css_class("error");
+                widgets.preview.set_text(&gettext("Invalid format"));
+
+                None
+            }
+        }
+    }
+
+    fn notification_times(&self, widgets: &ModelWidgets) -> Option<Vec<chrono::NaiveTime>> {
+        let times = crate::date::parse_times(&widgets.notification_times.text());
+
+        if times.is_some() {
+            widgets.notification_times.remove_css_class("error");
+        } else {
+            widgets.notification_times.add_css_class("error");
+        }
+
+        times
+    }
+
+    fn changed(&mut self, widgets: &ModelWidgets, sender: relm4::ComponentSender<Self>) {
+        self.format(widgets);
+        self.notification_times(widgets);
+        widgets
+            .notification_times
+            .set_sensitive(widgets.notifications.is_active());
+
+        self.generation = self.generation.wrapping_add(1);
+        let generation = self.generation;
+
+        gtk::glib::timeout_add_local_once(Self::DELAY, move || {
+            sender.input(MsgInput::Timeout(generation));
+        });
+    }
+
+    fn apply(&mut self, widgets: &ModelWidgets, sender: relm4::ComponentSender<Self>) {
+        let priority = match widgets.priority.selected() {
+            0 => 26,
+            n => n as u8 - 1,
+        };
+
+        let preferences = Preferences {
+            agenda_month: widgets.agenda_month.value() as u32,
+            agenda_week: widgets.agenda_week.value() as u32,
+            blocker: widgets.blocker.text().trim().to_string(),
+            date_format: self
+                .format(widgets)
+                .unwrap_or_else(|| self.preferences.date_format.clone()),
+            hidden_pages: self
+                .pages
+                .iter()
+                .filter(|(_, button)| !button.is_active())
+                .map(|(page, _)| *page)
+                .collect(),
+            log_level: LOG_LEVELS
+                .get(widgets.log_level.selected() as usize)
+                .copied()
+                .unwrap_or(log::LevelFilter::Info),
+            notification_times: self
+                .notification_times(widgets)
+                .unwrap_or_else(|| self.preferences.notification_times.clone()),
+            notifications: widgets.notifications.is_active(),
+            priority,
+            startup_page: match widgets.startup_page.selected() {
+                0 => None,
+                n => Page::customizable().nth(n as usize - 1),
+            },
+            tab_counts: widgets.tab_counts.is_active(),
+            theme: Theme::ALL
+                .get(widgets.theme.selected() as usize)
+                .copied()
+                .unwrap_or_default(),
+            week_start: WEEKDAYS
+                .get(widgets.week_start.selected() as usize)
+                .copied()
+                .unwrap_or(chrono::Weekday::Mon),
+        };
+
+        if preferences != self.preferences {
+            self.preferences = preferences;
+            self.preferences.save();
+            sender.output(self.preferences.clone()).ok();
+        }
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = Preferences;
+    type Input = MsgInput;
+    type Output = Preferences;
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let model = Self {
+            generation: 0,
+            pages: Self::page_buttons(&init.hidden_pages, &sender),
+            preferences: init,
+        };
+
+        let widgets = view_output!();
+
+        for (_, button) in &model.pages {
+            widgets.pages.append(button);
+        }
+
+        model.format(&widgets);
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        _: &Self::Root,
+    ) {
+        match msg {
+            MsgInput::Apply => self.apply(widgets, sender),
+            MsgInput::Changed => self.changed(widgets, sender),
+            MsgInput::Timeout(generation) => {
+                if generation == self.generation {
+                    self.apply(widgets, sender);
+                }
+            }
+        }
+    }
+
+    view! {
+        gtk::Window {
+            set_hide_on_close: true,
+            set_modal: true,
+            set_resizable: false,
+            set_title: Some(&gettext("Preferences")),
+
+            gtk::Grid {
+                set_column_spacing: 10,
+                set_margin_bottom: 10,
+                set_margin_end: 10,
+                set_margin_start: 10,
+                set_margin_top: 10,
+                set_row_spacing: 5,
+
+                attach[0, 0, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Date format"),
+                    set_xalign: 1.,
+                },
+                #[name = "date_format"]
+                attach[1, 0, 1, 1] = &gtk::Entry {
+                    set_text: &model.preferences.date_format,
+                    set_tooltip_text: Some(&gettext("strftime format, for example %d/%m/%Y")),
+                    connect_activate => MsgInput::Apply,
+                    connect_changed => MsgInput::Changed,
+                },
+                #[name = "preview"]
+                attach[1, 1, 1, 1] = &gtk::Label {
+                    add_css_class: "preview",
+                    set_xalign: 0.,
+                },
+                attach[0, 2, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Week starts on"),
+                    set_xalign: 1.,
+                },
+                #[name = "week_start"]
+                attach[1, 2, 1, 1] = &Self::weekdays(model.preferences.week_start) {
+                    connect_selected_notify => MsgInput::Changed,
+                },
+                attach[0, 3, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Default priority"),
+                    set_xalign: 1.,
+                },
+                #[name = "priority"]
+                attach[1, 3, 1, 1] = &Self::priorities(model.preferences.priority) {
+                    connect_selected_notify => MsgInput::Changed,
+                },
+                attach[0, 4, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Theme"),
+                    set_xalign: 1.,
+                },
+                #[name = "theme"]
+                attach[1, 4, 1, 1] = &Self::themes(model.preferences.theme) {
+                    connect_selected_notify => MsgInput::Changed,
+                },
+                attach[0, 5, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Agenda week (days)"),
+                    set_xalign: 1.,
+                },
+                #[name = "agenda_week"]
+                attach[1, 5, 1, 1] = &gtk::SpinButton::with_range(3., 31., 1.) {
+                    set_value: model.preferences.agenda_week.into(),
+                    connect_value_changed => MsgInput::Changed,
+                },
+                attach[0, 6, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Agenda month (days)"),
+                    set_xalign: 1.,
+                },
+                #[name = "agenda_month"]
+                attach[1, 6, 1, 1] = &gtk::SpinButton::with_range(8., 365., 1.) {
+                    set_value: model.preferences.agenda_month.into(),
+                    connect_value_changed => MsgInput::Changed,
+                },
+                attach[0, 7, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Startup page"),
+                    set_xalign: 1.,
+                },
+                #[name = "startup_page"]
+                attach[1, 7, 1, 1] = &Self::startup_pages(model.preferences.startup_page) {
+                    connect_selected_notify => MsgInput::Changed,
+                },
+                attach[0, 8, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Visible pages"),
+                    set_xalign: 1.,
+                    set_yalign: 0.,
+                },
+                #[name = "pages"]
+                attach[1, 8, 1, 1] = &gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                },
+                attach[0, 9, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Show counts in tabs"),
+                    set_xalign: 1.,
+                },
+                #[name = "tab_counts"]
+                attach[1, 9, 1, 1] = &gtk::Switch {
+                    set_active: model.preferences.tab_counts,
+                    set_halign: gtk::Align::Start,
+                    connect_active_notify => MsgInput::Changed,
+                },
+                attach[0, 10, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Blocker keyword"),
+                    set_xalign: 1.,
+                },
+                #[name = "blocker"]
+                attach[1, 10, 1, 1] = &gtk::Entry {
+                    set_text: &model.preferences.blocker,
+                    set_tooltip_text: Some(&gettext("Project, context, hashtag or tag marking blocked tasks in the standup report")),
+                    connect_activate => MsgInput::Apply,
+                    connect_changed => MsgInput::Changed,
+                },
+                attach[0, 11, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Log level"),
+                    set_xalign: 1.,
+                },
+                #[name = "log_level"]
+                attach[1, 11, 1, 1] = &Self::log_levels(model.preferences.log_level) {
+                    set_tooltip_text: Some(&gettext("Overridden by the RUST_LOG environment variable")),
+                    connect_selected_notify => MsgInput::Changed,
+                },
+                attach[0, 12, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Desktop notifications"),
+                    set_xalign: 1.,
+                },
+                #[name = "notifications"]
+                attach[1, 12, 1, 1] = &gtk::Switch {
+                    set_active: model.preferences.notifications,
+                    set_halign: gtk::Align::Start,
+                    set_tooltip_text: Some(&gettext("Notify about tasks due today, overdue tasks and deferred tasks becoming available")),
+                    connect_active_notify => MsgInput::Changed,
+                },
+                attach[0, 13, 1, 1] = &gtk::Label {
+                    set_text: &gettext("Notification times"),
+                    set_xalign: 1.,
+                },
+                #[name = "notification_times"]
+                attach[1, 13, 1, 1] = &gtk::Entry {
+                    set_sensitive: model.preferences.notifications,
+                    set_text: &crate::date::format_times(&model.preferences.notification_times),
+                    set_tooltip_text: Some(&gettext("Comma separated times, for example 09:00, 14:00. Notifications are also sent at startup")),
+                    connect_activate => MsgInput::Apply,
+                    connect_changed => MsgInput::Changed,
+                },
+            },
+            connect_close_request[sender] => move |_| {
+                sender.input(MsgInput::Apply);
+                gtk::glib::Propagation::Proceed
+            },
+        }
+    }
+}
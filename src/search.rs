@@ -11,19 +11,24 @@ pub enum MsgInput {
 }
 
 pub struct Model {
+    sort: relm4::Controller<crate::widgets::sort::Model>,
     tasks: relm4::Controller<crate::widgets::tasks::Model>,
+    visibility: relm4::Controller<crate::widgets::visibility::Model>,
 }
 
 impl Model {
-    fn tasks() -> Vec<crate::tasks::Task> {
+    fn tasks(visibility: &crate::tasks::Visibility) -> Vec<crate::tasks::Task> {
         let current_filter = CURRENT_FILTER.read().unwrap();
 
         let filter = current_filter.to_lowercase();
+        let today = crate::date::today();
         let list = crate::application::tasks();
 
         list.tasks
             .iter()
-            .filter(|x| x.subject.to_lowercase().contains(filter.as_str()))
+            .filter(|x| {
+                x.subject.to_lowercase().contains(filter.as_str()) && visibility.matches(x, today)
+            })
             .cloned()
             .collect()
     }
@@ -46,7 +51,19 @@ impl relm4::SimpleComponent for Model {
             .launch(crate::Filter::from(Self::tasks))
             .forward(sender.output_sender(), std::convert::identity);
 
-        let model = Self { tasks };
+        let sort = crate::widgets::sort::Model::builder()
+            .launch(("search", crate::tasks::Sort::default()))
+            .forward(tasks.sender(), crate::widgets::tasks::MsgInput::Sort);
+
+        let visibility = crate::widgets::visibility::Model::builder()
+            .launch(("search", crate::tasks::Visibility::ALL))
+            .forward(tasks.sender(), crate::widgets::tasks::MsgInput::Visibility);
+
+        let model = Self {
+            sort,
+            tasks,
+            visibility,
+        };
 
         let widgets = view_output!();
 
@@ -68,6 +85,15 @@ impl relm4::SimpleComponent for Model {
 
     view! {
         gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            gtk::Box {
+                set_halign: gtk::Align::End,
+                set_orientation: gtk::Orientation::Horizontal,
+
+                append: model.visibility.widget(),
+                append: model.sort.widget(),
+            },
             append: model.tasks.widget(),
         }
     }
@@ -0,0 +1,69 @@
+thread_local! {
+    static SETTINGS: gtk::glib::KeyFile = load();
+}
+
+pub fn config_file(name: &str) -> std::path::PathBuf {
+    gtk::glib::user_config_dir()
+        .join(crate::application::NAME)
+        .join(name)
+}
+
+pub fn load_file(name: &str) -> gtk::glib::KeyFile {
+    let keyfile = gtk::glib::KeyFile::new();
+    let path = config_file(name);
+
+    if path.exists()
+        && let Err(err) = keyfile.load_from_file(&path, gtk::glib::KeyFileFlags::KEEP_COMMENTS)
+    {
+        log::warn!("Unable to load {path:?}: {err}");
+    }
+
+    keyfile
+}
+
+#[cfg(not(test))]
+fn load() -> gtk::glib::KeyFile {
+    load_file("settings.ini")
+}
+
+#[cfg(test)]
+fn load() -> gtk::glib::KeyFile {
+    gtk::glib::KeyFile::new()
+}
+
+#[cfg(not(test))]
+fn save(settings: &gtk::glib::KeyFile) {
+    let path = config_file("settings.ini");
+
+    if let Some(parent) = path.parent()
+        && let Err(err) = std::fs::create_dir_all(parent)
+    {
+        log::warn!("Unable to create {parent:?}: {err}");
+        return;
+    }
+
+    if let Err(err) = settings.save_to_file(&path) {
+        log::warn!("Unable to save settings to {path:?}: {err}");
+    }
+}
+
+#[cfg(test)]
+fn save(_: &gtk::glib::KeyFile) {}
+
+pub fn get<T: std::str::FromStr>(group: &str, key: &str) -> Option<T> {
+    SETTINGS.with(|settings| settings.string(group, key).ok()?.parse().ok())
+}
+
+pub fn set<T: ToString>(group: &str, key: &str, value: T) {
+    set_all(group, &[(key, value.to_string())]);
+}
+
+pub fn set_all(group: &str, values: &[(&str, String)]) {
+    SETTINGS.with(|settings| {
+        for (key, value) in values {
+            settings.set_string(group, key, value);
+        }
+
+        save(settings);
+    });
+}
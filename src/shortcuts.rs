@@ -0,0 +1,593 @@
+use crate::i18n::{gettext, gettext_noop};
+
+type Binding = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    Option<&'static str>,
+    &'static [&'static str],
+);
+
+static GROUPS: &[(&str, &str)] = &[
+    ("general", gettext_noop("General")),
+    ("pages", gettext_noop("Pages")),
+    ("navigation", gettext_noop("Navigation")),
+    ("tasks", gettext_noop("Tasks")),
+];
+
+static DEFAULTS: &[Binding] = &[
+    (
+        "general",
+        "add",
+        gettext_noop("New task"),
+        "add",
+        None,
+        &["<Control>A"],
+    ),
+    (
+        "general",
+        "find",
+        gettext_noop("Search"),
+        "find",
+        None,
+        &["<Control>F", "F3"],
+    ),
+    (
+        "general",
+        "refresh",
+        gettext_noop("Refresh tasks"),
+        "refresh",
+        None,
+        &["<Control>R", "F5"],
+    ),
+    (
+        "general",
+        "help",
+        gettext_noop("Keyboard shortcuts"),
+        "help",
+        None,
+        &["<Control>question", "F1"],
+    ),
+    (
+        "general",
+        "palette",
+        gettext_noop("Command palette"),
+        "palette",
+        None,
+        &["<Control><Shift>P"],
+    ),
+    (
+        "pages",
+        "inbox",
+        gettext_noop("Inbox"),
+        "page",
+        Some("inbox"),
+        &["1"],
+    ),
+    (
+        "pages",
+        "projects",
+        gettext_noop("Projects"),
+        "page",
+        Some("projects"),
+        &["2"],
+    ),
+    (
+        "pages",
+        "contexts",
+        gettext_noop("Contexts"),
+        "page",
+        Some("contexts"),
+        &["3"],
+    ),
+    (
+        "pages",
+        "tags",
+        gettext_noop("Tags"),
+        "page",
+        Some("tags"),
+        &["4"],
+    ),
+    (
+        "pages",
+        "agenda",
+        gettext_noop("Agenda"),
+        "page",
+        Some("agenda"),
+        &["5"],
+    ),
+    (
+        "pages",
+        "flag",
+        gettext_noop("Flag"),
+        "page",
+        Some("flag"),
+        &["6"],
+    ),
+    (
+        "pages",
+        "done",
+        gettext_noop("Done"),
+        "page",
+        Some("done"),
+        &["7"],
+    ),
+    (
+        "pages",
+        "statistics",
+        gettext_noop("Statistics"),
+        "page",
+        Some("statistics"),
+        &["8"],
+    ),
+    (
+        "pages",
+        "search",
+        gettext_noop("Search"),
+        "page",
+        Some("search"),
+        &["9"],
+    ),
+    (
+        "navigation",
+        "next",
+        gettext_noop("Next task"),
+        "next",
+        None,
+        &["j"],
+    ),
+    (
+        "navigation",
+        "previous",
+        gettext_noop("Previous task"),
+        "previous",
+        None,
+        &["k"],
+    ),
+    (
+        "tasks",
+        "complete",
+        gettext_noop("Complete/uncomplete"),
+        "complete",
+        None,
+        &["space", "<Control>Return"],
+    ),
+    (
+        "tasks",
+        "edit",
+        gettext_noop("Edit"),
+        "edit",
+        None,
+        &["e", "Return"],
+    ),
+    (
+        "tasks",
+        "flag",
+        gettext_noop("Flag/unflag"),
+        "flag",
+        None,
+        &["f", "<Control>I"],
+    ),
+    (
+        "tasks",
+        "priority-up",
+        gettext_noop("Raise priority"),
+        "priority",
+        Some("up"),
+        &["plus", "KP_Add"],
+    ),
+    (
+        "tasks",
+        "priority-down",
+        gettext_noop("Lower priority"),
+        "priority",
+        Some("down"),
+        &["minus", "KP_Subtract"],
+    ),
+    (
+        "tasks",
+        "priority-a",
+        gettext_noop("Priority A"),
+        "priority",
+        Some("A"),
+        &["<Alt>A"],
+    ),
+    (
+        "tasks",
+        "priority-b",
+        gettext_noop("Priority B"),
+        "priority",
+        Some("B"),
+        &["<Alt>B"],
+    ),
+    (
+        "tasks",
+        "priority-c",
+        gettext_noop("Priority C"),
+        "priority",
+        Some("C"),
+        &["<Alt>C"],
+    ),
+    (
+        "tasks",
+        "priority-d",
+        gettext_noop("Priority D"),
+        "priority",
+        Some("D"),
+        &["<Alt>D"],
+    ),
+    (
+        "tasks",
+        "priority-e",
+        gettext_noop("Priority E"),
+        "priority",
+        Some("E"),
+        &["<Alt>E"],
+    ),
+    (
+        "tasks",
+        "priority-none",
+        gettext_noop("Remove priority"),
+        "priority",
+        Some("none"),
+        &["<Alt>Z"],
+    ),
+    (
+        "tasks",
+        "defer-day",
+        gettext_noop("Defer one day"),
+        "defer",
+        Some("1d"),
+        &["d", "<Control>1"],
+    ),
+    (
+        "tasks",
+        "defer-week",
+        gettext_noop("Defer one week"),
+        "defer",
+        Some("1w"),
+        &["<Control>2"],
+    ),
+    (
+        "tasks",
+        "defer-month",
+        gettext_noop("Defer one month"),
+        "defer",
+        Some("1m"),
+        &["<Control>3"],
+    ),
+    (
+        "tasks",
+        "due-today",
+        gettext_noop("Due today"),
+        "due",
+        Some("today"),
+        &["<Control>T"],
+    ),
+    (
+        "tasks",
+        "due-tomorrow",
+        gettext_noop("Due tomorrow"),
+        "due",
+        Some("tomorrow"),
+        &["<Control><Shift>T"],
+    ),
+    (
+        "tasks",
+        "copy",
+        gettext_noop("Copy"),
+        "copy",
+        None,
+        &["<Control>C"],
+    ),
+    (
+        "tasks",
+        "links",
+        gettext_noop("Open links"),
+        "links",
+        None,
+        &["<Control>O"],
+    ),
+    (
+        "tasks",
+        "note",
+        gettext_noop("Open note"),
+        "note",
+        None,
+        &["<Control>N"],
+    ),
+    (
+        "tasks",
+        "duplicate",
+        gettext_noop("Duplicate"),
+        "duplicate",
+        None,
+        &["<Control>D"],
+    ),
+    (
+        "tasks",
+        "delete",
+        gettext_noop("Delete"),
+        "delete",
+        None,
+        &["Delete"],
+    ),
+    (
+        "tasks",
+        "menu",
+        gettext_noop("Context menu"),
+        "menu",
+        None,
+        &["Menu", "<Shift>F10"],
+    ),
+];
+
+thread_local! {
+    static SHORTCUTS: Vec<Shortcut> = load();
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shortcut {
+    pub group: &'static str,
+    pub name: &'static str,
+    pub title: &'static str,
+    pub action: &'static str,
+    pub target: Option<&'static str>,
+    pub triggers: Vec<String>,
+    custom: bool,
+}
+
+pub fn all() -> Vec<Shortcut> {
+    SHORTCUTS.with(Clone::clone)
+}
+
+pub fn group(group: &str) -> Vec<Shortcut> {
+    SHORTCUTS.with(|shortcuts| {
+        shortcuts
+            .iter()
+            .filter(|x| x.group == group)
+            .cloned()
+            .collect()
+    })
+}
+
+fn load() -> Vec<Shortcut> {
+    let keyfile = crate::settings::load_file("shortcuts.ini");
+
+    let mut shortcuts = configure(|group, name| {
+        keyfile
+            .string_list(group, name)
+            .ok()
+            .map(|x| x.iter().map(ToString::to_string).collect())
+    });
+
+    for shortcut in &mut shortcuts {
+        shortcut.triggers.retain(|trigger| {
+            let valid = gtk::ShortcutTrigger::parse_string(trigger).is_some();
+
+            if !valid {
+                log::warn!(
+                    "Invalid shortcut '{trigger}' for {}.{}, ignored",
+                    shortcut.group,
+                    shortcut.name
+                );
+            }
+
+            valid
+        });
+    }
+
+    for conflict in resolve_conflicts(&mut shortcuts) {
+        log::warn!("{conflict}");
+    }
+
+    shortcuts
+}
+
+fn configure(custom: impl Fn(&str, &str) -> Option<Vec<String>>) -> Vec<Shortcut> {
+    DEFAULTS
+        .iter()
+        .map(|&(group, name, title, action, target, triggers)| {
+            let custom = custom(group, name).map(|triggers| {
+                triggers
+                    .iter()
+                    .map(|x| x.trim().to_string())
+                    .filter(|x| !x.is_empty())
+                    .collect()
+            });
+
+            Shortcut {
+                group,
+                name,
+                title,
+                action,
+                target,
+                custom: custom.is_some(),
+                triggers: custom
+                    .unwrap_or_else(|| triggers.iter().map(ToString::to_string).collect()),
+            }
+        })
+        .collect()
+}
+
+fn resolve_conflicts(shortcuts: &mut [Shortcut]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    let mut bound = std::collections::HashMap::new();
+
+    let mut order = (0..shortcuts.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| !shortcuts[i].custom);
+
+    for i in order {
+        let id = format!("{}.{}", shortcuts[i].group, shortcuts[i].name);
+
+        shortcuts[i]
+            .triggers
+            .retain(|trigger| match bound.entry(normalize(trigger)) {
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    entry.insert(id.clone());
+                    true
+                }
+                std::collections::hash_map::Entry::Occupied(entry) if *entry.get() == id => false,
+                std::collections::hash_map::Entry::Occupied(entry) => {
+                    conflicts.push(format!(
+                        "Shortcut '{trigger}' of {id} is already bound to {}, ignored",
+                        entry.get()
+                    ));
+                    false
+                }
+            });
+    }
+
+    conflicts
+}
+
+fn normalize(trigger: &str) -> String {
+    let mut modifiers = Vec::new();
+    let mut key = trigger.trim();
+
+    while let Some(rest) = key.strip_prefix('<')
+        && let Some((modifier, rest)) = rest.split_once('>')
+    {
+        let modifier = match modifier.to_lowercase().as_str() {
+            "ctrl" | "ctl" | "control" | "primary" => "control".to_string(),
+            "alt" | "mod1" => "alt".to_string(),
+            modifier => modifier.to_string(),
+        };
+
+        modifiers.push(modifier);
+        key = rest;
+    }
+
+    modifiers.sort();
+    modifiers.dedup();
+
+    let modifiers = modifiers
+        .iter()
+        .map(|x| format!("<{x}>"))
+        .collect::<String>();
+
+    format!("{modifiers}{}", key.to_lowercase())
+}
+
+pub fn ui(shortcuts: &[Shortcut]) -> String {
+    let escape = |text: &str| {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    };
+
+    let mut ui = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<interface>
+  <object class="GtkShortcutsWindow" id="shortcuts">
+    <property name="modal">1</property>
+    <child>
+      <object class="GtkShortcutsSection">
+        <property name="section-name">shortcuts</property>
+"#,
+    );
+
+    for (group, title) in GROUPS {
+        let shortcuts = shortcuts
+            .iter()
+            .filter(|x| x.group == *group && !x.triggers.is_empty())
+            .collect::<Vec<_>>();
+
+        if shortcuts.is_empty() {
+            continue;
+        }
+
+        ui.push_str(&format!(
+            r#"        <child>
+          <object class="GtkShortcutsGroup">
+            <property name="title">{}</property>
+"#,
+            escape(&gettext(title))
+        ));
+
+        for shortcut in shortcuts {
+            ui.push_str(&format!(
+                r#"            <child>
+              <object class="GtkShortcutsShortcut">
+                <property name="accelerator">{}</property>
+                <property name="title">{}</property>
+              </object>
+            </child>
+"#,
+                escape(&shortcut.triggers.join(" ")),
+                escape(&gettext(shortcut.title))
+            ));
+        }
+
+        ui.push_str(
+            r#"          </object>
+        </child>
+"#,
+        );
+    }
+
+    ui.push_str(
+        r#"      </object>
+    </child>
+  </object>
+</interface>
+"#,
+    );
+
+    ui
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::shortcuts::*;
+
+    #[test]
+    fn defaults() {
+        let mut shortcuts = configure(|_, _| None);
+
+        assert!(resolve_conflicts(&mut shortcuts).is_empty());
+        assert_eq!(shortcuts, configure(|_, _| None));
+    }
+
+    #[test]
+    fn conflicts() {
+        let mut shortcuts = configure(|group, name| match (group, name) {
+            ("tasks", "duplicate") => Some(vec!["d".to_string(), " <ctrl>d ".to_string()]),
+            ("tasks", "copy") => Some(vec!["<Primary>C".to_string(), "<ctl>c".to_string()]),
+            _ => None,
+        });
+
+        let conflicts = resolve_conflicts(&mut shortcuts);
+        let triggers = |name: &str| {
+            shortcuts
+                .iter()
+                .find(|x| x.name == name)
+                .map(|x| x.triggers.clone())
+                .unwrap()
+        };
+
+        assert_eq!(triggers("duplicate"), vec!["d", "<ctrl>d"]);
+        assert_eq!(triggers("defer-day"), vec!["<Control>1"]);
+        assert_eq!(triggers("copy"), vec!["<Primary>C"]);
+        assert_eq!(
+            conflicts,
+            vec!["Shortcut 'd' of tasks.defer-day is already bound to tasks.duplicate, ignored"]
+        );
+    }
+
+    #[test]
+    fn ui() {
+        let shortcuts = configure(|group, name| match (group, name) {
+            ("general", "add") => Some(vec!["<Control>plus".to_string()]),
+            ("general", "help") => Some(Vec::new()),
+            _ => None,
+        });
+
+        let ui = crate::shortcuts::ui(&shortcuts);
+
+        assert!(ui.contains("&lt;Control&gt;plus"));
+        assert!(ui.contains("<property name=\"title\">Defer one week</property>"));
+        assert!(!ui.contains("Keyboard shortcuts"));
+    }
+}
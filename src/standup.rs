@@ -0,0 +1,191 @@
+use gtk::prelude::*;
+
+use crate::i18n::gettext;
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Copy,
+    EditTemplate,
+    Export,
+    Save(std::path::PathBuf),
+    Show,
+}
+
+pub struct Model {
+    chooser: Option<gtk::FileChooserNative>,
+}
+
+impl Model {
+    fn report() -> String {
+        let list = crate::application::tasks();
+        let standup = crate::tasks::standup::Standup::new(
+            &list.tasks,
+            crate::date::today(),
+            &crate::application::preferences().blocker,
+        );
+
+        standup.render(&crate::tasks::standup::template())
+    }
+
+    fn text(widgets: &ModelWidgets) -> String {
+        let buffer = widgets.text_view.buffer();
+
+        buffer
+            .text(&buffer.start_iter(), &buffer.end_iter(), false)
+            .to_string()
+    }
+
+    fn edit_template() {
+        let path = crate::tasks::standup::template_path();
+
+        if !path.exists() {
+            if let Some(parent) = path.parent()
+                && let Err(err) = std::fs::create_dir_all(parent)
+            {
+                log::error!("Unable to create {parent:?}: {err}");
+                return;
+            }
+
+            if let Err(err) = std::fs::write(&path, crate::tasks::standup::TEMPLATE) {
+                log::error!("Unable to create {path:?}: {err}");
+                return;
+            }
+        }
+
+        let uri = gtk::gio::File::for_path(&path).uri();
+
+        if let Err(err) =
+            gtk::gio::AppInfo::launch_default_for_uri(&uri, None::<&gtk::gio::AppLaunchContext>)
+        {
+            log::error!("Unable to open {path:?}: {err}");
+        }
+    }
+
+    fn export(&mut self, root: &gtk::Window, sender: relm4::ComponentSender<Self>) {
+        let chooser = gtk::FileChooserNative::new(
+            Some(&gettext("Save standup report")),
+            Some(root),
+            gtk::FileChooserAction::Save,
+            Some(&gettext("Save")),
+            Some(&gettext("Cancel")),
+        );
+        chooser.set_current_name(&format!("standup-{}.md", crate::date::today()));
+
+        chooser.connect_response(move |chooser, response| {
+            if response == gtk::ResponseType::Accept
+                && let Some(path) = chooser.file().and_then(|x| x.path())
+            {
+                sender.input(MsgInput::Save(path));
+            }
+        });
+
+        chooser.show();
+        self.chooser = Some(chooser);
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = ();
+    type Input = MsgInput;
+    type Output = ();
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let model = Self { chooser: None };
+
+        let widgets = view_output!();
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        match msg {
+            MsgInput::Copy => {
+                root.clipboard().set_text(&Self::text(widgets));
+                log::info!("Standup report copied to clipboard");
+            }
+            MsgInput::EditTemplate => Self::edit_template(),
+            MsgInput::Export => self.export(root, sender),
+            MsgInput::Save(path) => match std::fs::write(&path, Self::text(widgets)) {
+                Ok(()) => log::info!("Standup report saved to {path:?}"),
+                Err(err) => log::error!("Unable to save standup report: {err}"),
+            },
+            MsgInput::Show => {
+                widgets.text_view.buffer().set_text(&Self::report());
+                root.present();
+            }
+        }
+    }
+
+    view! {
+        gtk::Window {
+            set_default_size: (500, 400),
+            set_hide_on_close: true,
+            set_title: Some(&gettext("Standup report")),
+
+            gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                set_margin_bottom: 10,
+                set_margin_end: 10,
+                set_margin_start: 10,
+                set_margin_top: 10,
+
+                gtk::ScrolledWindow {
+                    set_vexpand: true,
+
+                    #[name = "text_view"]
+                    gtk::TextView {
+                        set_monospace: true,
+                        set_wrap_mode: gtk::WrapMode::Word,
+                        update_property: &[gtk::accessible::Property::Label(&gettext("Standup report"))],
+                    },
+                },
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 5,
+
+                    gtk::Button {
+                        set_label: &gettext("Edit template"),
+
+                        connect_clicked => MsgInput::EditTemplate,
+                    },
+                    gtk::Button {
+                        set_label: &gettext("Refresh"),
+
+                        connect_clicked => MsgInput::Show,
+                    },
+                    gtk::Box {
+                        set_halign: gtk::Align::End,
+                        set_hexpand: true,
+                        set_orientation: gtk::Orientation::Horizontal,
+                        set_spacing: 5,
+
+                        gtk::Button {
+                            set_label: &gettext("Save…"),
+
+                            connect_clicked => MsgInput::Export,
+                        },
+                        gtk::Button {
+                            add_css_class: "suggested-action",
+                            set_label: &gettext("Copy"),
+
+                            connect_clicked => MsgInput::Copy,
+                        },
+                    },
+                },
+            },
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use gtk::prelude::*;
+
+use crate::i18n::{gettext, gettext_noop, ngettext, pgettext};
+use crate::tasks::statistics::{Breakdown, Period, Statistics};
+
+#[derive(Debug)]
+pub enum Msg {
+    From(Option<chrono::NaiveDate>),
+    Period,
+    To(Option<chrono::NaiveDate>),
+    Update,
+}
+
+pub struct Model {
+    completed: relm4::Controller<crate::widgets::chart::Model>,
+    flow: relm4::Controller<crate::widgets::chart::Model>,
+    from: Option<chrono::NaiveDate>,
+    from_calendar: relm4::Controller<crate::widgets::calendar::Model>,
+    overdue: relm4::Controller<crate::widgets::chart::Model>,
+    period: Period,
+    to: Option<chrono::NaiveDate>,
+    to_calendar: relm4::Controller<crate::widgets::calendar::Model>,
+}
+
+impl Model {
+    const BLUE: (f64, f64, f64) = (0.27, 0.6, 0.91);
+    const GREEN: (f64, f64, f64) = (0.45, 0.75, 0.3);
+    const RED: (f64, f64, f64) = (1., 0.4, 0.5);
+
+    fn range(&self) -> (chrono::NaiveDate, chrono::NaiveDate) {
+        let to = self.to.unwrap_or_else(crate::date::today);
+        let from = self.from.unwrap_or(to - chrono::Months::new(3));
+
+        (from, to)
+    }
+
+    fn days(days: Option<f64>) -> String {
+        match days {
+            Some(days) => ngettext("{days} day", "{days} days", days.round() as u32)
+                .replace("{days}", &format!("{days:.1}")),
+            None => "—".to_string(),
+        }
+    }
+
+    fn update_statistics(&self, widgets: &ModelWidgets) {
+        use crate::widgets::chart::{Data, Kind, MsgInput, Series};
+        use relm4::ComponentController as _;
+
+        let list = crate::application::tasks();
+        let week_start = crate::application::preferences().week_start;
+        let statistics = Statistics::new(&list.tasks, self.range(), self.period, week_start);
+
+        widgets.summary.set_text(
+            &gettext("{created} created, {completed} completed, average cycle time: {cycle_time}")
+                .replace("{created}", &statistics.created.to_string())
+                .replace("{completed}", &statistics.completed.to_string())
+                .replace("{cycle_time}", &Self::days(statistics.cycle_time)),
+        );
+
+        let labels = statistics
+            .buckets
+            .iter()
+            .map(|x| self.period.format(x.start))
+            .collect::<Vec<_>>();
+        let values = |f: fn(&crate::tasks::statistics::Bucket) -> usize| {
+            statistics
+                .buckets
+                .iter()
+                .map(|x| f(x) as f64)
+                .collect::<Vec<_>>()
+        };
+
+        self.completed.emit(MsgInput::Set(Data {
+            kind: Kind::Bars,
+            labels: labels.clone(),
+            markers: Vec::new(),
+            series: vec![Series {
+                label: pgettext("statistics", "Completed"),
+                color: Self::BLUE,
+                start: 0,
+                values: values(|x| x.completed),
+            }],
+        }));
+
+        self.flow.emit(MsgInput::Set(Data {
+            kind: Kind::Bars,
+            labels: labels.clone(),
+            markers: Vec::new(),
+            series: vec![
+                Series {
+                    label: pgettext("statistics", "Created"),
+                    color: Self::GREEN,
+                    start: 0,
+                    values: values(|x| x.created),
+                },
+                Series {
+                    label: pgettext("statistics", "Completed"),
+                    color: Self::BLUE,
+                    start: 0,
+                    values: values(|x| x.completed),
+                },
+            ],
+        }));
+
+        self.overdue.emit(MsgInput::Set(Data {
+            kind: Kind::Line,
+            labels,
+            markers: Vec::new(),
+            series: vec![Series {
+                label: gettext("Overdue"),
+                color: Self::RED,
+                start: 0,
+                values: values(|x| x.overdue),
+            }],
+        }));
+
+        Self::fill(&widgets.projects, &gettext("Project"), &statistics.projects);
+        Self::fill(&widgets.contexts, &gettext("Context"), &statistics.contexts);
+    }
+
+    fn fill(grid: &gtk::Grid, title: &str, rows: &[Breakdown]) {
+        use relm4::RelmRemoveAllExt as _;
+
+        grid.remove_all();
+
+        let headers = [
+            title.to_string(),
+            pgettext("statistics", "Created"),
+            pgettext("statistics", "Completed"),
+            gettext("Cycle time"),
+        ];
+
+        for (column, header) in headers.iter().enumerate() {
+            let label = gtk::Label::new(Some(header));
+            label.add_css_class("heading");
+            label.set_xalign(if column == 0 { 0. } else { 1. });
+            grid.attach(&label, column as i32, 0, 1, 1);
+        }
+
+        for (row, breakdown) in rows.iter().enumerate() {
+            let cells = [
+                breakdown.name.clone(),
+                breakdown.created.to_string(),
+                breakdown.completed.to_string(),
+                Self::days(breakdown.cycle_time),
+            ];
+
+            for (column, cell) in cells.iter().enumerate() {
+                let label = gtk::Label::new(Some(cell));
+                label.set_xalign(if column == 0 { 0. } else { 1. });
+                grid.attach(&label, column as i32, row as i32 + 1, 1, 1);
+            }
+        }
+    }
+
+    fn dropdown(selected: Period) -> gtk::DropDown {
+        let labels = Period::ALL.iter().map(Period::label).collect::<Vec<_>>();
+        let dropdown =
+            gtk::DropDown::from_strings(&labels.iter().map(String::as_str).collect::<Vec<_>>());
+
+        if let Some(position) = Period::ALL.iter().position(|x| *x == selected) {
+            dropdown.set_selected(position as u32);
+        }
+
+        dropdown
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = ();
+    type Input = Msg;
+    type Output = ();
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        use relm4::Component as _;
+        use relm4::ComponentController as _;
+
+        let chart = |title: &'static str| {
+            crate::widgets::chart::Model::builder()
+                .launch(title)
+                .detach()
+        };
+
+        let from_calendar = crate::widgets::calendar::Model::builder()
+            .launch(gettext_noop("From"))
+            .forward(sender.input_sender(), |output| match output {
+                crate::widgets::calendar::MsgOutput::Updated(date) => Msg::From(date),
+            });
+
+        let to_calendar = crate::widgets::calendar::Model::builder()
+            .launch(gettext_noop("To"))
+            .forward(sender.input_sender(), |output| match output {
+                crate::widgets::calendar::MsgOutput::Updated(date) => Msg::To(date),
+            });
+
+        let model = Self {
+            completed: chart(gettext_noop("Tasks completed")),
+            flow: chart(gettext_noop("Tasks created vs completed")),
+            from: None,
+            from_calendar,
+            overdue: chart(gettext_noop("Overdue tasks")),
+            period: crate::settings::get("statistics", "period").unwrap_or_default(),
+            to: None,
+            to_calendar,
+        };
+
+        let (from, to) = model.range();
+        model
+            .from_calendar
+            .emit(crate::widgets::calendar::MsgInput::Set(Some(from)));
+        model
+            .to_calendar
+            .emit(crate::widgets::calendar::MsgInput::Set(Some(to)));
+
+        let widgets = view_output!();
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        _: relm4::ComponentSender<Self>,
+        _: &Self::Root,
+    ) {
+        match msg {
+            Msg::From(date) => self.from = date,
+            Msg::Period => {
+                self.period = Period::ALL
+                    .get(widgets.period.selected() as usize)
+                    .copied()
+                    .unwrap_or_default();
+                crate::settings::set("statistics", "period", self.period);
+            }
+            Msg::To(date) => self.to = date,
+            Msg::Update => (),
+        }
+
+        self.update_statistics(widgets);
+    }
+
+    view! {
+        gtk::ScrolledWindow {
+            set_policy: (gtk::PolicyType::Never, gtk::PolicyType::Automatic),
+
+            gtk::Box {
+                set_margin_bottom: 10,
+                set_margin_end: 10,
+                set_margin_start: 10,
+                set_margin_top: 10,
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 10,
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 10,
+
+                    append: model.from_calendar.widget(),
+                    append: model.to_calendar.widget(),
+                    #[name = "period"]
+                    append = &Self::dropdown(model.period) {
+                        set_valign: gtk::Align::Start,
+
+                        connect_selected_notify => Msg::Period,
+                    },
+                },
+                #[name = "summary"]
+                gtk::Label {
+                    set_xalign: 0.,
+                },
+                gtk::Label {
+                    add_css_class: "heading",
+                    set_text: &gettext("Tasks completed"),
+                    set_xalign: 0.,
+                },
+                append: model.completed.widget(),
+                gtk::Label {
+                    add_css_class: "heading",
+                    set_text: &gettext("Tasks created vs completed"),
+                    set_xalign: 0.,
+                },
+                append: model.flow.widget(),
+                gtk::Label {
+                    add_css_class: "heading",
+                    set_text: &gettext("Overdue tasks"),
+                    set_xalign: 0.,
+                },
+                append: model.overdue.widget(),
+                gtk::Box {
+                    set_homogeneous: true,
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 20,
+
+                    #[name = "projects"]
+                    gtk::Grid {
+                        set_column_spacing: 10,
+                        set_row_spacing: 5,
+                        set_valign: gtk::Align::Start,
+                    },
+                    #[name = "contexts"]
+                    gtk::Grid {
+                        set_column_spacing: 10,
+                        set_row_spacing: 5,
+                        set_valign: gtk::Align::Start,
+                    },
+                },
+            },
+        }
+    }
+}
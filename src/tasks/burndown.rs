@@ -0,0 +1,132 @@
+#[derive(Clone, Debug, PartialEq)]
+pub struct Burndown {
+    pub start: chrono::NaiveDate,
+    pub today: chrono::NaiveDate,
+    pub remaining: Vec<usize>,
+    pub velocity: f64,
+    pub forecast: Option<chrono::NaiveDate>,
+    pub due: Option<chrono::NaiveDate>,
+}
+
+impl Burndown {
+    const WINDOW: i64 = 28;
+    const HORIZON: i64 = 365;
+
+    pub fn new(tasks: &[&super::Task], today: chrono::NaiveDate) -> Self {
+        let start = tasks
+            .iter()
+            .filter_map(|x| x.create_date.or(x.finish_date))
+            .min()
+            .unwrap_or(today)
+            .max(today - chrono::Duration::days(Self::HORIZON))
+            .min(today);
+
+        let remaining = start
+            .iter_days()
+            .take_while(|x| *x <= today)
+            .map(|date| tasks.iter().filter(|x| Self::open(x, date)).count())
+            .collect::<Vec<_>>();
+
+        let since = today - chrono::Duration::days(Self::WINDOW);
+        let completed = tasks
+            .iter()
+            .filter(|x| x.finished && x.finish_date.is_some_and(|x| x > since && x <= today))
+            .count();
+        let velocity = completed as f64 / Self::WINDOW as f64;
+
+        let left = remaining.last().copied().unwrap_or_default();
+        let forecast = (left > 0 && completed > 0).then(|| {
+            let days = (left * Self::WINDOW as usize).div_ceil(completed);
+
+            today + chrono::Duration::days(days as i64)
+        });
+
+        Self {
+            start,
+            today,
+            remaining,
+            velocity,
+            forecast,
+            due: tasks.iter().filter_map(|x| x.due_date).max(),
+        }
+    }
+
+    fn open(task: &super::Task, date: chrono::NaiveDate) -> bool {
+        let created = task.create_date.is_none_or(|x| x <= date);
+        let finished = task.finished && task.finish_date.is_none_or(|x| x <= date);
+
+        created && !finished
+    }
+
+    pub fn left(&self) -> usize {
+        self.remaining.last().copied().unwrap_or_default()
+    }
+
+    pub fn late(&self) -> bool {
+        let Some(due) = self.due else {
+            return false;
+        };
+
+        self.left() > 0 && self.forecast.is_none_or(|x| x > due)
+    }
+
+    pub fn end(&self) -> chrono::NaiveDate {
+        [Some(self.today), self.forecast, self.due]
+            .into_iter()
+            .flatten()
+            .max()
+            .unwrap_or(self.today)
+            .min(self.today + chrono::Duration::days(Self::HORIZON))
+    }
+
+    pub fn projection(&self, date: chrono::NaiveDate) -> f64 {
+        let days = (date - self.today).num_days() as f64;
+
+        (self.left() as f64 - self.velocity * days).max(0.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::burndown::*;
+
+    #[test]
+    fn burndown() {
+        let date = |d| chrono::NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let tasks = [
+            "x 2026-10-02 2026-10-01 write spec +app",
+            "x 2026-10-08 2026-10-01 build +app",
+            "2026-10-01 test +app due:2026-10-20",
+            "2026-10-03 release +app due:2026-10-10",
+            "2026-10-05 announce +app",
+        ]
+        .iter()
+        .map(|x| x.parse::<crate::tasks::Task>().unwrap())
+        .collect::<Vec<_>>();
+        let tasks = tasks.iter().collect::<Vec<_>>();
+
+        let burndown = Burndown::new(&tasks, date(15));
+
+        assert_eq!(burndown.start, date(1));
+        assert_eq!(burndown.remaining.len(), 15);
+        assert_eq!(burndown.remaining[0], 3);
+        assert_eq!(burndown.remaining[1], 2);
+        assert_eq!(burndown.remaining[4], 4);
+        assert_eq!(burndown.remaining[7], 3);
+        assert_eq!(burndown.left(), 3);
+        assert_eq!(burndown.velocity, 2. / 28.);
+        assert_eq!(
+            burndown.forecast,
+            Some(date(15) + chrono::Duration::days(42))
+        );
+        assert_eq!(burndown.due, Some(date(20)));
+        assert!(burndown.late());
+        assert_eq!(burndown.end(), date(15) + chrono::Duration::days(42));
+        assert!((burndown.projection(date(29)) - 2.).abs() < 1e-9);
+
+        let done = Burndown::new(&tasks[..2], date(15));
+        assert_eq!(done.left(), 0);
+        assert_eq!(done.forecast, None);
+        assert!(!done.late());
+    }
+}
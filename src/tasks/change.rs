@@ -0,0 +1,195 @@
+#[derive(Clone, Debug)]
+pub enum Change {
+    AddTag(String),
+    Complete,
+    Delete,
+    Due(Option<chrono::NaiveDate>),
+    Duplicate,
+    Flag(bool),
+    MoveTag(String),
+    Priority(todo_txt::Priority),
+    RemoveTag(String),
+    Threshold(Option<chrono::NaiveDate>),
+}
+
+impl Change {
+    pub fn apply(&self, task: &mut super::Task) {
+        use Change::*;
+
+        match self {
+            AddTag(tag) => Self::add_tag(task, tag),
+            Complete => task.complete(),
+            Delete | Duplicate => (),
+            Due(date) => task.due_date = *date,
+            Flag(flagged) => task.flagged = *flagged,
+            MoveTag(tag) => Self::move_tag(task, tag),
+            Priority(priority) => task.priority = priority.clone(),
+            RemoveTag(tag) => Self::remove_tag(task, tag),
+            Threshold(date) => task.threshold_date = *date,
+        }
+    }
+
+    pub fn from_action(task: &super::Task, action: &str, target: Option<&str>) -> Option<Self> {
+        let change = match (action, target) {
+            ("defer", Some(period)) => {
+                let today = crate::date::today();
+                let from = task.threshold_date.map_or(today, |x| x.max(today));
+
+                Change::Threshold(crate::date::parse_from(
+                    &format!("+{period}"),
+                    from,
+                    crate::application::preferences().week_start,
+                ))
+            }
+            ("delete", _) => Change::Delete,
+            ("due", Some(date)) => Change::Due(crate::date::parse(date)),
+            ("duplicate", _) => Change::Duplicate,
+            ("flag", _) => Change::Flag(!task.flagged),
+            ("priority", Some("none")) => Change::Priority(26.into()),
+            ("priority", Some("up")) => {
+                let priority = u8::from(task.priority.clone());
+
+                Change::Priority(priority.saturating_sub(1).into())
+            }
+            ("priority", Some("down")) => {
+                let priority = u8::from(task.priority.clone());
+
+                Change::Priority((priority + 1).min(26).into())
+            }
+            ("priority", Some(letter)) => match letter.as_bytes() {
+                [c @ b'A'..=b'Z'] => Change::Priority((c - b'A').into()),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(change)
+    }
+
+    fn add_tag(task: &mut super::Task, tag: &str) {
+        if let Some((key, value)) = Self::keyword(tag) {
+            task.tags.insert(key.to_string(), value.to_string());
+            return;
+        }
+
+        if task.subject.split_whitespace().any(|x| x == tag) {
+            return;
+        }
+
+        let Some((sigil, name)) = Self::split(tag) else {
+            return;
+        };
+
+        task.subject.push(' ');
+        task.subject.push_str(tag);
+
+        Self::tags(task, sigil).push(name.to_string());
+    }
+
+    fn move_tag(task: &mut super::Task, tag: &str) {
+        let Some((sigil, _)) = Self::split(tag) else {
+            return;
+        };
+
+        for name in Self::tags(task, sigil).clone() {
+            Self::remove_tag(task, &format!("{sigil}{name}"));
+        }
+
+        Self::add_tag(task, tag);
+    }
+
+    fn remove_tag(task: &mut super::Task, tag: &str) {
+        if let Some((key, _)) = Self::keyword(tag) {
+            task.tags.remove(key);
+            return;
+        }
+
+        let Some((sigil, name)) = Self::split(tag) else {
+            return;
+        };
+
+        task.subject = task
+            .subject
+            .split_whitespace()
+            .filter(|x| *x != tag)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Self::tags(task, sigil).retain(|x| x != name);
+    }
+
+    fn tags(task: &mut super::Task, sigil: char) -> &mut Vec<String> {
+        match sigil {
+            '+' => &mut task.projects,
+            '@' => &mut task.contexts,
+            _ => &mut task.hashtags,
+        }
+    }
+
+    fn split(tag: &str) -> Option<(char, &str)> {
+        let sigil = tag.chars().next()?;
+
+        if !matches!(sigil, '+' | '@' | '#') || tag.len() == 1 || tag.contains(char::is_whitespace)
+        {
+            return None;
+        }
+
+        Some((sigil, &tag[1..]))
+    }
+
+    fn keyword(tag: &str) -> Option<(&str, &str)> {
+        if Self::split(tag).is_some() {
+            return None;
+        }
+
+        tag.split_once(':')
+            .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::change::*;
+
+    #[test]
+    fn move_tag() {
+        let mut task = "call bob +work +home @phone"
+            .parse::<crate::tasks::Task>()
+            .unwrap();
+
+        Change::MoveTag("+admin\\mail".to_string()).apply(&mut task);
+
+        assert_eq!(task.subject, "call bob @phone +admin\\mail");
+        assert_eq!(task.projects, vec!["admin\\mail".to_string()]);
+        assert_eq!(task.contexts, vec!["phone".to_string()]);
+
+        Change::AddTag("@office".to_string()).apply(&mut task);
+
+        assert_eq!(task.subject, "call bob @phone +admin\\mail @office");
+        assert_eq!(
+            task.contexts,
+            vec!["phone".to_string(), "office".to_string()]
+        );
+    }
+
+    #[test]
+    fn from_action() {
+        let task = "(C) call bob".parse::<crate::tasks::Task>().unwrap();
+        let priority = |target| match Change::from_action(&task, "priority", Some(target)) {
+            Some(Change::Priority(priority)) => Some(u8::from(priority)),
+            _ => None,
+        };
+
+        assert_eq!(priority("up"), Some(1));
+        assert_eq!(priority("down"), Some(3));
+        assert_eq!(priority("A"), Some(0));
+        assert_eq!(priority("none"), Some(26));
+        assert_eq!(priority("a"), None);
+        assert!(matches!(
+            Change::from_action(&task, "flag", None),
+            Some(Change::Flag(true))
+        ));
+        assert!(Change::from_action(&task, "edit", None).is_none());
+        assert!(Change::from_action(&task, "due", None).is_none());
+    }
+}
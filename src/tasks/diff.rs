@@ -0,0 +1,138 @@
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Diff {
+    changes: Vec<(Option<super::Task>, Option<super::Task>)>,
+}
+
+impl Diff {
+    pub fn new(before: &super::List, after: &super::List) -> Self {
+        let mut changes = Vec::new();
+        let mut kept = Vec::new();
+        let mut tasks = after.tasks.iter().peekable();
+
+        while let Some(task) = tasks
+            .next_if(|x| x.id < before.tasks.len() && kept.last().is_none_or(|last| x.id > *last))
+        {
+            kept.push(task.id);
+
+            if *task != before.tasks[task.id] {
+                changes.push((Some(before.tasks[task.id].clone()), Some(task.clone())));
+            }
+        }
+
+        for (id, task) in before.tasks.iter().enumerate() {
+            if !kept.contains(&id) {
+                changes.push((Some(task.clone()), None));
+            }
+        }
+
+        changes.extend(tasks.map(|x| (None, Some(x.clone()))));
+
+        Self { changes }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self {
+            changes: self
+                .changes
+                .iter()
+                .map(|(before, after)| (after.clone(), before.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn apply(&self, list: &mut super::List) -> Result<(), String> {
+        let mut replaced = Vec::new();
+        let mut appended = Vec::new();
+
+        for (before, after) in &self.changes {
+            let Some(before) = before else {
+                appended.extend(after.clone());
+                continue;
+            };
+
+            let line = before.to_string();
+            let Some(index) =
+                list.tasks.iter().enumerate().position(|(n, x)| {
+                    !replaced.iter().any(|(i, _)| *i == n) && x.to_string() == line
+                })
+            else {
+                return Err(line);
+            };
+
+            replaced.push((index, after.clone()));
+        }
+
+        replaced.sort_unstable_by_key(|(index, _)| *index);
+
+        for (index, after) in replaced.into_iter().rev() {
+            match after {
+                Some(mut task) => {
+                    task.id = list.tasks[index].id;
+                    list.tasks[index] = task;
+                }
+                None => {
+                    list.tasks.remove(index);
+                }
+            }
+        }
+
+        for task in appended {
+            list.append(task);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::diff::*;
+
+    fn list(lines: &[&str]) -> crate::tasks::List {
+        let mut list = crate::tasks::List::new();
+
+        for (id, line) in lines.iter().enumerate() {
+            let mut task = line.parse::<crate::tasks::Task>().unwrap();
+            task.id = id;
+            list.append(task);
+        }
+
+        list
+    }
+
+    fn lines(list: &crate::tasks::List) -> Vec<String> {
+        list.tasks.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn diff() {
+        let before = list(&["a", "b", "c"]);
+
+        let mut after = before.clone();
+        after.apply(&[1], &crate::tasks::Change::Flag(true));
+        after.delete(&[2]);
+        after.duplicate(0);
+
+        let diff = Diff::new(&before, &after);
+
+        let mut external = list(&["new", "a", "b", "c"]);
+        diff.apply(&mut external).unwrap();
+        let mut expected = vec!["new".to_string()];
+        expected.extend(lines(&after));
+        assert_eq!(lines(&external), expected);
+
+        diff.inverse().apply(&mut external).unwrap();
+        assert_eq!(lines(&external), ["new", "a", "b", "c"]);
+
+        let mut modified = list(&["a", "b changed", "c"]);
+        assert_eq!(diff.apply(&mut modified), Err("b".to_string()));
+        assert_eq!(lines(&modified), ["a", "b changed", "c"]);
+    }
+
+    #[test]
+    fn empty() {
+        let before = list(&["a", "b"]);
+
+        assert_eq!(Diff::new(&before, &before.clone()), Diff::default());
+    }
+}
@@ -1,17 +1,11 @@
 macro_rules! tags {
-    ($self:ident, $kind:ident) => {{
+    ($self:ident, $kind:ident, $visibility:ident) => {{
         let today = crate::date::today();
-        let preferences = crate::application::preferences();
 
         $self
             .inner
             .iter()
-            .filter(|x| {
-                (preferences.done || !x.finished)
-                    && (preferences.defered
-                        || x.threshold_date.is_none()
-                        || x.threshold_date.unwrap() <= today)
-            })
+            .filter(|x| $visibility.matches(x, today))
             .collect::<todo_txt::task::List<_>>()
             .$kind()
     }};
@@ -76,16 +70,16 @@ impl List {
         tasks
     }
 
-    pub fn projects(&self) -> Vec<String> {
-        tags!(self, projects)
+    pub fn projects(&self, visibility: &super::Visibility) -> Vec<String> {
+        tags!(self, projects, visibility)
     }
 
-    pub fn contexts(&self) -> Vec<String> {
-        tags!(self, contexts)
+    pub fn contexts(&self, visibility: &super::Visibility) -> Vec<String> {
+        tags!(self, contexts, visibility)
     }
 
-    pub fn hashtags(&self) -> Vec<String> {
-        tags!(self, hashtags)
+    pub fn hashtags(&self, visibility: &super::Visibility) -> Vec<String> {
+        tags!(self, hashtags, visibility)
     }
 
     pub fn write(&self) -> Result<(), String> {
@@ -143,20 +137,107 @@ impl List {
     }
 
     pub fn add(&mut self, text: &str) -> Result<(), String> {
-        use std::str::FromStr as _;
+        let tasks = super::parser::parse(text, crate::application::preferences().priority);
 
-        let mut task = crate::tasks::Task::from_str(text)
-            .map_err(|_| format!("Unable to convert task: '{text}'"))?;
+        if tasks.is_empty() {
+            return Err(format!("Unable to convert task: '{text}'"));
+        }
 
-        task.create_date = Some(crate::date::today());
+        for mut task in tasks {
+            task.create_date = Some(crate::date::today());
+            self.append(task);
+        }
 
-        self.append(task);
-        self.write()
+        Ok(())
     }
 
     pub fn append(&mut self, task: crate::tasks::Task) {
         self.inner.push(task);
     }
+
+    pub fn apply(&mut self, ids: &[usize], change: &super::Change) {
+        if matches!(change, super::Change::Delete) {
+            self.delete(ids);
+            return;
+        }
+
+        for id in ids {
+            match change {
+                super::Change::Complete => self.complete(*id),
+                super::Change::Duplicate => self.duplicate(*id),
+                _ => {
+                    if let Some(task) = self.inner.tasks.get_mut(*id) {
+                        change.apply(task);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn delete(&mut self, ids: &[usize]) {
+        let mut ids = ids.to_vec();
+        ids.sort_unstable();
+        ids.dedup();
+
+        for id in ids.into_iter().rev() {
+            if id < self.inner.tasks.len() {
+                self.inner.tasks.remove(id);
+            }
+        }
+    }
+
+    pub fn duplicate(&mut self, id: usize) {
+        let Some(task) = self.inner.tasks.get(id) else {
+            return;
+        };
+
+        let mut new = task.clone();
+        new.uncomplete();
+        new.create_date = Some(crate::date::today());
+
+        self.append(new);
+    }
+
+    pub fn complete(&mut self, id: usize) {
+        let Some(task) = self.inner.tasks.get_mut(id) else {
+            return;
+        };
+
+        if task.finished {
+            return;
+        }
+
+        task.complete();
+
+        let t = task.clone();
+
+        if let Some(ref recurrence) = t.recurrence {
+            let due = if recurrence.strict
+                && let Some(due_date) = t.due_date
+            {
+                due_date
+            } else {
+                crate::date::today()
+            };
+
+            let mut new: crate::tasks::Task = t.clone();
+            new.uncomplete();
+            new.create_date = Some(crate::date::today());
+            new.due_date = Some(recurrence.clone() + due);
+
+            if let Some(threshold_date) = t.threshold_date {
+                new.threshold_date = Some(recurrence.clone() + threshold_date);
+            }
+
+            self.append(new);
+        }
+    }
+
+    pub fn uncomplete(&mut self, id: usize) {
+        if let Some(task) = self.inner.tasks.get_mut(id) {
+            task.uncomplete();
+        }
+    }
 }
 
 impl std::ops::Deref for List {
@@ -0,0 +1,97 @@
+use super::sort::{Group, Key, Sort};
+
+pub fn markdown(
+    tasks: &[super::Task],
+    (from, to): (chrono::NaiveDate, chrono::NaiveDate),
+    today: chrono::NaiveDate,
+) -> String {
+    use crate::i18n::gettext;
+
+    let tasks = tasks
+        .iter()
+        .filter(|x| x.finished && x.finish_date.is_some_and(|x| x >= from && x <= to))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let sort = Sort {
+        primary: Key::File,
+        secondary: Key::File,
+        group: Group::Day,
+    };
+
+    let mut markdown = format!(
+        "# {}\n",
+        gettext("Done from {from} to {to}")
+            .replace("{from}", &crate::date::format(from))
+            .replace("{to}", &crate::date::format(to))
+    );
+
+    for (title, tasks) in sort.apply(tasks, today) {
+        markdown.push_str(&format!("\n## {}\n\n", title.unwrap_or_default()));
+
+        if let Some(summary) = sort.group.summary(&tasks) {
+            markdown.push_str(&format!("{summary}\n\n"));
+        }
+
+        for task in tasks {
+            markdown.push_str(&format!("- {}\n", task.subject));
+        }
+    }
+
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::logbook::*;
+
+    #[test]
+    fn markdown() {
+        let date = |d| chrono::NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let tasks = [
+            "x 2026-10-14 call bob +work",
+            "x 2026-10-02 write report +work",
+            "x 2026-10-15 buy milk",
+            "x 2026-10-14 pay bills +home",
+            "2026-10-14 read a book",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(id, line)| {
+            let mut task = line.parse::<crate::tasks::Task>().unwrap();
+            task.id = id;
+            task
+        })
+        .collect::<Vec<_>>();
+
+        let format = |d| crate::date::format(date(d));
+        let weekday = |d| crate::date::format_with(date(d), "%A").unwrap();
+
+        assert_eq!(
+            super::markdown(&tasks, (date(10), date(15)), date(15)),
+            format!(
+                "# Done from {} to {}
+
+## {} {}
+
+1 task
+
+- buy milk
+
+## {} {}
+
+2 tasks · +home 1, +work 1
+
+- call bob +work
+- pay bills +home
+",
+                format(10),
+                format(15),
+                weekday(15),
+                format(15),
+                weekday(14),
+                format(14),
+            )
+        );
+    }
+}
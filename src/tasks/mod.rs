@@ -1,7 +1,21 @@
+pub mod burndown;
+mod change;
+mod diff;
 mod list;
+pub mod logbook;
 pub mod markup;
+pub mod parser;
+pub mod reminder;
+pub mod sort;
+pub mod standup;
+pub mod statistics;
 pub mod task;
+pub mod visibility;
 
+pub use change::Change;
+pub use diff::Diff;
 pub use list::List;
 pub use markup::Markup;
+pub use sort::Sort;
 pub use task::Task;
+pub use visibility::Visibility;
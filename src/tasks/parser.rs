@@ -0,0 +1,94 @@
+pub fn parse(text: &str, default_priority: u8) -> Vec<super::Task> {
+    text.lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| parse_line(x, default_priority))
+        .collect()
+}
+
+fn parse_line(line: &str, default_priority: u8) -> super::Task {
+    use std::str::FromStr as _;
+
+    let mut flagged = false;
+    let mut priority = None;
+
+    let line = crate::date::normalize(line);
+    let subject = line
+        .split_whitespace()
+        .filter(|word| {
+            if *word == "*" {
+                flagged = true;
+            } else if let Some(p) = self::priority(word) {
+                priority = Some(p);
+            } else {
+                return true;
+            }
+
+            false
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut task = super::Task::from_str(&subject).unwrap();
+
+    if let Some(priority) = priority {
+        task.priority = priority;
+    } else if task.priority.is_lowest() {
+        task.priority = default_priority.into();
+    }
+
+    if flagged {
+        task.flagged = true;
+    }
+
+    task
+}
+
+fn priority(word: &str) -> Option<todo_txt::Priority> {
+    let letter = word.strip_prefix('!')?;
+
+    match letter.as_bytes() {
+        [c] if c.is_ascii_alphabetic() => Some((c.to_ascii_uppercase() - b'A').into()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::parser::*;
+
+    #[test]
+    fn shorthands() {
+        let tasks = parse(
+            "!b call bob +work @phone *\n\n  !A second task due:2042-01-01\n",
+            26,
+        );
+
+        assert_eq!(tasks.len(), 2);
+
+        assert_eq!(tasks[0].subject, "call bob +work @phone");
+        assert_eq!(u8::from(tasks[0].priority.clone()), 1);
+        assert_eq!(tasks[0].projects, vec!["work".to_string()]);
+        assert_eq!(tasks[0].contexts, vec!["phone".to_string()]);
+        assert!(tasks[0].flagged);
+
+        assert_eq!(tasks[1].subject, "second task");
+        assert_eq!(u8::from(tasks[1].priority.clone()), 0);
+        assert_eq!(
+            tasks[1].due_date,
+            chrono::NaiveDate::from_ymd_opt(2042, 1, 1)
+        );
+        assert!(!tasks[1].flagged);
+    }
+
+    #[test]
+    fn default_priority() {
+        let tasks = parse("(A) explicit\n!c shorthand\nnone", 3);
+
+        assert_eq!(u8::from(tasks[0].priority.clone()), 0);
+        assert_eq!(u8::from(tasks[1].priority.clone()), 2);
+        assert_eq!(u8::from(tasks[2].priority.clone()), 3);
+
+        assert!(parse("none", 26)[0].priority.is_lowest());
+    }
+}
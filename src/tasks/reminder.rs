@@ -0,0 +1,254 @@
+use crate::i18n::gettext;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Kind {
+    Due,
+    Overdue,
+    Threshold,
+}
+
+impl Kind {
+    pub fn title(self) -> String {
+        match self {
+            Self::Due => gettext("Task due today"),
+            Self::Overdue => gettext("Overdue task"),
+            Self::Threshold => gettext("Deferred task available"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reminder {
+    pub kind: Kind,
+    pub task: super::Task,
+}
+
+impl Reminder {
+    pub fn id(&self) -> String {
+        id(&self.task)
+    }
+}
+
+pub fn id(task: &super::Task) -> String {
+    task.to_string()
+}
+
+pub fn reminders(tasks: &[super::Task], today: chrono::NaiveDate) -> Vec<Reminder> {
+    tasks
+        .iter()
+        .filter(|x| !x.finished && !x.hidden)
+        .filter_map(|task| {
+            let kind = match (task.due_date, task.threshold_date) {
+                (_, Some(threshold)) if threshold > today => return None,
+                (Some(due), _) if due < today => Kind::Overdue,
+                (Some(due), _) if due == today => Kind::Due,
+                (_, Some(threshold)) if threshold == today => Kind::Threshold,
+                _ => return None,
+            };
+
+            Some(Reminder {
+                kind,
+                task: task.clone(),
+            })
+        })
+        .collect()
+}
+
+pub trait Notifier {
+    fn send(&mut self, reminder: &Reminder);
+    fn withdraw(&mut self, id: &str);
+}
+
+#[derive(Debug, Default)]
+pub struct Scheduler {
+    last: Option<chrono::NaiveDateTime>,
+    shown: std::collections::HashSet<String>,
+    snoozed: std::collections::HashMap<String, chrono::NaiveDateTime>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn check(
+        &mut self,
+        notifier: &mut impl Notifier,
+        tasks: &[super::Task],
+        times: &[chrono::NaiveTime],
+        now: chrono::NaiveDateTime,
+    ) {
+        let reminders = reminders(tasks, now.date());
+        let triggered = self.triggered(times, now);
+        self.last = Some(now);
+
+        let ids = reminders
+            .iter()
+            .map(Reminder::id)
+            .collect::<std::collections::HashSet<_>>();
+
+        self.snoozed.retain(|id, _| ids.contains(id));
+
+        for id in self.shown.difference(&ids) {
+            notifier.withdraw(id);
+        }
+        self.shown.retain(|id| ids.contains(id));
+
+        for reminder in &reminders {
+            let id = reminder.id();
+
+            match self.snoozed.get(&id) {
+                Some(until) if *until > now => continue,
+                Some(_) => {
+                    self.snoozed.remove(&id);
+                }
+                None if !triggered => continue,
+                None => (),
+            }
+
+            notifier.send(reminder);
+            self.shown.insert(id);
+        }
+    }
+
+    fn triggered(&self, times: &[chrono::NaiveTime], now: chrono::NaiveDateTime) -> bool {
+        let Some(last) = self.last else {
+            return true;
+        };
+
+        times.iter().any(|time| {
+            let at = now.date().and_time(*time);
+
+            last < at && at <= now
+        })
+    }
+
+    pub fn snooze(&mut self, notifier: &mut impl Notifier, id: &str, until: chrono::NaiveDateTime) {
+        if self.shown.remove(id) {
+            notifier.withdraw(id);
+        }
+
+        self.snoozed.insert(id.to_string(), until);
+    }
+
+    pub fn clear(&mut self, notifier: &mut impl Notifier) {
+        for id in self.shown.drain() {
+            notifier.withdraw(&id);
+        }
+
+        self.last = None;
+        self.snoozed.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::reminder::*;
+
+    #[derive(Default)]
+    struct Mock {
+        sent: Vec<String>,
+        withdrawn: Vec<String>,
+    }
+
+    impl Mock {
+        fn take(&mut self) -> (Vec<String>, Vec<String>) {
+            (
+                std::mem::take(&mut self.sent),
+                std::mem::take(&mut self.withdrawn),
+            )
+        }
+    }
+
+    impl Notifier for Mock {
+        fn send(&mut self, reminder: &Reminder) {
+            self.sent.push(reminder.task.subject.clone());
+        }
+
+        fn withdraw(&mut self, id: &str) {
+            self.withdrawn
+                .push(id.parse::<crate::tasks::Task>().unwrap().subject.clone());
+        }
+    }
+
+    fn tasks(lines: &[&str]) -> Vec<crate::tasks::Task> {
+        lines.iter().map(|x| x.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn reminders() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let tasks = tasks(&[
+            "due due:2026-10-15",
+            "overdue due:2026-10-10",
+            "threshold t:2026-10-15",
+            "deferred due:2026-10-10 t:2026-10-16",
+            "later due:2026-10-16",
+            "x done due:2026-10-15",
+            "hidden due:2026-10-15 h:1",
+        ]);
+
+        let reminders = super::reminders(&tasks, today)
+            .into_iter()
+            .map(|x| (x.task.subject.clone(), x.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            reminders,
+            [
+                ("due".to_string(), Kind::Due),
+                ("overdue".to_string(), Kind::Overdue),
+                ("threshold".to_string(), Kind::Threshold),
+            ]
+        );
+    }
+
+    #[test]
+    fn scheduler() {
+        let at = |h, m| {
+            chrono::NaiveDate::from_ymd_opt(2026, 10, 15)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap()
+        };
+        let times = [
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(14, 0, 0).unwrap(),
+        ];
+        let mut tasks = tasks(&[
+            "due due:2026-10-15",
+            "overdue due:2026-10-10",
+            "threshold t:2026-10-15",
+        ]);
+        let mut mock = Mock::default();
+        let mut scheduler = Scheduler::new();
+
+        scheduler.check(&mut mock, &tasks, &times, at(8, 0));
+        assert_eq!(mock.take().0, ["due", "overdue", "threshold"]);
+
+        scheduler.check(&mut mock, &tasks, &times, at(8, 30));
+        assert_eq!(mock.take(), (Vec::new(), Vec::new()));
+
+        scheduler.snooze(&mut mock, &id(&tasks[1]), at(9, 30));
+        assert_eq!(mock.take().1, ["overdue"]);
+
+        scheduler.check(&mut mock, &tasks, &times, at(9, 0));
+        assert_eq!(mock.take().0, ["due", "threshold"]);
+
+        scheduler.check(&mut mock, &tasks, &times, at(9, 30));
+        assert_eq!(mock.take().0, ["overdue"]);
+
+        tasks[0] = "x 2026-10-15 due due:2026-10-15".parse().unwrap();
+        scheduler.check(&mut mock, &tasks, &times, at(10, 0));
+        assert_eq!(mock.take(), (Vec::new(), vec!["due".to_string()]));
+
+        scheduler.check(&mut mock, &tasks, &times, at(14, 1));
+        assert_eq!(mock.take().0, ["overdue", "threshold"]);
+
+        scheduler.clear(&mut mock);
+        assert_eq!(mock.take().1.len(), 2);
+
+        scheduler.check(&mut mock, &tasks, &times, at(14, 2));
+        assert_eq!(mock.take().0, ["overdue", "threshold"]);
+    }
+}
@@ -0,0 +1,501 @@
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Key {
+    #[default]
+    Priority,
+    Due,
+    Threshold,
+    Created,
+    Finished,
+    Alphabetical,
+    File,
+}
+
+impl Key {
+    pub const ALL: [Self; 7] = [
+        Self::Priority,
+        Self::Due,
+        Self::Threshold,
+        Self::Created,
+        Self::Finished,
+        Self::Alphabetical,
+        Self::File,
+    ];
+
+    pub fn label(&self) -> String {
+        use crate::i18n::gettext;
+
+        match self {
+            Self::Priority => gettext("Priority"),
+            Self::Due => gettext("Due date"),
+            Self::Threshold => gettext("Threshold date"),
+            Self::Created => gettext("Creation date"),
+            Self::Finished => gettext("Finish date"),
+            Self::Alphabetical => gettext("Alphabetical"),
+            Self::File => gettext("File order"),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Priority => "priority",
+            Self::Due => "due",
+            Self::Threshold => "threshold",
+            Self::Created => "created",
+            Self::Finished => "finished",
+            Self::Alphabetical => "alphabetical",
+            Self::File => "file",
+        }
+    }
+
+    pub fn compare(&self, a: &super::Task, b: &super::Task) -> std::cmp::Ordering {
+        match self {
+            Self::Priority => u8::from(a.priority.clone()).cmp(&u8::from(b.priority.clone())),
+            Self::Due => Self::compare_dates(a.due_date, b.due_date),
+            Self::Threshold => Self::compare_dates(a.threshold_date, b.threshold_date),
+            Self::Created => Self::compare_dates(a.create_date, b.create_date),
+            Self::Finished => Self::compare_dates(a.finish_date, b.finish_date),
+            Self::Alphabetical => a.subject.to_lowercase().cmp(&b.subject.to_lowercase()),
+            Self::File => a.id.cmp(&b.id),
+        }
+    }
+
+    fn compare_dates(
+        a: Option<chrono::NaiveDate>,
+        b: Option<chrono::NaiveDate>,
+    ) -> std::cmp::Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl std::str::FromStr for Key {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|x| x.name() == s)
+            .ok_or_else(|| format!("Invalid sort key '{s}'"))
+    }
+}
+
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+type GroupKey = (u8, Option<std::cmp::Reverse<chrono::NaiveDate>>, String);
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Group {
+    #[default]
+    None,
+    Project,
+    Context,
+    Priority,
+    Due,
+    Day,
+    Week,
+    Month,
+}
+
+impl Group {
+    pub const ALL: [Self; 8] = [
+        Self::None,
+        Self::Project,
+        Self::Context,
+        Self::Priority,
+        Self::Due,
+        Self::Day,
+        Self::Week,
+        Self::Month,
+    ];
+
+    pub fn label(&self) -> String {
+        use crate::i18n::gettext;
+
+        match self {
+            Self::None => gettext("No grouping"),
+            Self::Project => gettext("Project"),
+            Self::Context => gettext("Context"),
+            Self::Priority => gettext("Priority"),
+            Self::Due => gettext("Due date"),
+            Self::Day => gettext("Completion day"),
+            Self::Week => gettext("Completion week"),
+            Self::Month => gettext("Completion month"),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Project => "project",
+            Self::Context => "context",
+            Self::Priority => "priority",
+            Self::Due => "due",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    fn key(
+        &self,
+        task: &super::Task,
+        today: chrono::NaiveDate,
+        week_start: chrono::Weekday,
+    ) -> GroupKey {
+        match self {
+            Self::None => (0, None, String::new()),
+            Self::Project => Self::first(&task.projects),
+            Self::Context => Self::first(&task.contexts),
+            Self::Priority => (u8::from(task.priority.clone()), None, String::new()),
+            Self::Due => {
+                let rank = match task.due_date {
+                    Some(due) if due < today => 0,
+                    Some(due) if due == today => 1,
+                    Some(due) if Some(due) == today.succ_opt() => 2,
+                    Some(due) if due <= crate::date::end_of_week(today, week_start) => 3,
+                    Some(_) => 4,
+                    None => 5,
+                };
+
+                (rank, None, String::new())
+            }
+            Self::Day | Self::Week | Self::Month => match task.finish_date {
+                Some(date) => (
+                    0,
+                    Some(std::cmp::Reverse(self.start(date, week_start))),
+                    String::new(),
+                ),
+                None => (1, None, String::new()),
+            },
+        }
+    }
+
+    fn start(&self, date: chrono::NaiveDate, week_start: chrono::Weekday) -> chrono::NaiveDate {
+        use super::statistics::Period;
+
+        let period = match self {
+            Self::Week => Period::Week,
+            Self::Month => Period::Month,
+            _ => Period::Day,
+        };
+
+        period.start(date, week_start)
+    }
+
+    fn title(&self, (rank, start, tag): &GroupKey) -> String {
+        use crate::i18n::gettext;
+
+        match (self, start) {
+            (Self::None, _) => String::new(),
+            (Self::Project, _) if *rank > 0 => gettext("No project"),
+            (Self::Context, _) if *rank > 0 => gettext("No context"),
+            (Self::Project | Self::Context, _) => tag.clone(),
+            (Self::Priority, _) => {
+                let priority = todo_txt::Priority::from(*rank);
+
+                if priority.is_lowest() {
+                    gettext("No priority")
+                } else {
+                    gettext("Priority {priority}")
+                        .replace("{priority}", &char::from(priority).to_string())
+                }
+            }
+            (Self::Due, _) => match rank {
+                0 => gettext("Overdue"),
+                1 => gettext("Today"),
+                2 => gettext("Tomorrow"),
+                3 => gettext("This week"),
+                4 => gettext("Later"),
+                _ => gettext("No due date"),
+            },
+            (Self::Day | Self::Week | Self::Month, None) => gettext("No completion date"),
+            (Self::Week, Some(std::cmp::Reverse(start))) => {
+                gettext("Week of {date}").replace("{date}", &crate::date::format(*start))
+            }
+            (Self::Month, Some(std::cmp::Reverse(start))) => {
+                crate::date::format_with(*start, "%B %Y").unwrap_or_default()
+            }
+            (_, Some(std::cmp::Reverse(start))) => format!(
+                "{} {}",
+                crate::date::format_with(*start, "%A").unwrap_or_default(),
+                crate::date::format(*start)
+            ),
+        }
+    }
+
+    pub fn summary(&self, tasks: &[super::Task]) -> Option<String> {
+        use crate::i18n::ngettext;
+
+        if !matches!(self, Self::Day | Self::Week | Self::Month) {
+            return None;
+        }
+
+        let mut projects = std::collections::BTreeMap::<&str, usize>::new();
+
+        for project in tasks.iter().flat_map(|x| &x.projects) {
+            *projects.entry(project).or_default() += 1;
+        }
+
+        let mut projects = projects.into_iter().collect::<Vec<_>>();
+        projects.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+
+        let count = ngettext("{count} task", "{count} tasks", tasks.len() as u32)
+            .replace("{count}", &tasks.len().to_string());
+
+        if projects.is_empty() {
+            return Some(count);
+        }
+
+        let projects = projects
+            .iter()
+            .map(|(project, count)| format!("+{project} {count}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Some(format!("{count} · {projects}"))
+    }
+
+    fn first(tags: &[String]) -> GroupKey {
+        match tags.first() {
+            Some(tag) => (0, None, tag.clone()),
+            None => (1, None, String::new()),
+        }
+    }
+}
+
+impl std::str::FromStr for Group {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|x| x.name() == s)
+            .ok_or_else(|| format!("Invalid group '{s}'"))
+    }
+}
+
+impl std::fmt::Display for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sort {
+    pub primary: Key,
+    pub secondary: Key,
+    pub group: Group,
+}
+
+impl Default for Sort {
+    fn default() -> Self {
+        Self {
+            primary: Key::Priority,
+            secondary: Key::Due,
+            group: Group::None,
+        }
+    }
+}
+
+impl Sort {
+    pub fn apply(
+        &self,
+        tasks: Vec<super::Task>,
+        today: chrono::NaiveDate,
+    ) -> Vec<(Option<String>, Vec<super::Task>)> {
+        let week_start = crate::application::preferences().week_start;
+        let mut tasks = tasks
+            .into_iter()
+            .map(|task| (self.group.key(&task, today, week_start), task))
+            .collect::<Vec<_>>();
+
+        tasks.sort_by(|(a_group, a), (b_group, b)| {
+            a_group
+                .cmp(b_group)
+                .then_with(|| self.primary.compare(a, b))
+                .then_with(|| self.secondary.compare(a, b))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let mut groups: Vec<(GroupKey, Vec<super::Task>)> = Vec::new();
+
+        for (key, task) in tasks {
+            match groups.last_mut() {
+                Some((last, tasks)) if *last == key => tasks.push(task),
+                _ => groups.push((key, vec![task])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(key, tasks)| {
+                let label = (self.group != Group::None).then(|| self.group.title(&key));
+
+                (label, tasks)
+            })
+            .collect()
+    }
+}
+
+impl std::str::FromStr for Sort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',').map(str::trim);
+
+        let sort = Self {
+            primary: parts.next().unwrap_or_default().parse()?,
+            secondary: parts.next().unwrap_or_default().parse()?,
+            group: parts.next().unwrap_or("none").parse()?,
+        };
+
+        Ok(sort)
+    }
+}
+
+impl std::fmt::Display for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.primary, self.secondary, self.group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::sort::*;
+
+    #[test]
+    fn apply() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let tasks = [
+            "(B) pay bills +home due:2026-10-20",
+            "write report +work due:2026-10-10",
+            "(A) call bob +work",
+            "(B) buy milk +home due:2026-10-16",
+            "read a book",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(id, line)| {
+            let mut task = line.parse::<crate::tasks::Task>().unwrap();
+            task.id = id;
+            task
+        })
+        .collect::<Vec<_>>();
+
+        let subjects = |groups: &[(Option<String>, Vec<crate::tasks::Task>)]| {
+            groups
+                .iter()
+                .map(|(label, tasks)| {
+                    (
+                        label.clone(),
+                        tasks.iter().map(|x| x.id).collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let sort = Sort::default();
+        assert_eq!(
+            subjects(&sort.apply(tasks.clone(), today)),
+            vec![(None, vec![2, 3, 0, 1, 4])]
+        );
+
+        let sort = "due,alphabetical,project".parse::<Sort>().unwrap();
+        assert_eq!(sort.to_string(), "due,alphabetical,project");
+        assert_eq!(
+            subjects(&sort.apply(tasks.clone(), today)),
+            vec![
+                (Some("home".to_string()), vec![3, 0]),
+                (Some("work".to_string()), vec![1, 2]),
+                (Some("No project".to_string()), vec![4]),
+            ]
+        );
+
+        let sort = "file,file,due".parse::<Sort>().unwrap();
+        assert_eq!(
+            subjects(&sort.apply(tasks, today)),
+            vec![
+                (Some("Overdue".to_string()), vec![1]),
+                (Some("Tomorrow".to_string()), vec![3]),
+                (Some("Later".to_string()), vec![0]),
+                (Some("No due date".to_string()), vec![2, 4]),
+            ]
+        );
+
+        assert!("size".parse::<Sort>().is_err());
+    }
+
+    #[test]
+    fn logbook() {
+        let date = |m, d| chrono::NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        let today = date(10, 15);
+        let tasks = [
+            "x 2026-10-14 call bob +work",
+            "x 2026-09-30 write report +work",
+            "x 2026-10-15 buy milk +home",
+            "x 2026-10-14 pay bills +home +work",
+            "x read a book",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(id, line)| {
+            let mut task = line.parse::<crate::tasks::Task>().unwrap();
+            task.id = id;
+            task
+        })
+        .collect::<Vec<_>>();
+
+        let sort = "file,file,day".parse::<Sort>().unwrap();
+        let groups = sort.apply(tasks.clone(), today);
+        let title = |date| {
+            format!(
+                "{} {}",
+                crate::date::format_with(date, "%A").unwrap(),
+                crate::date::format(date)
+            )
+        };
+
+        assert_eq!(
+            groups
+                .iter()
+                .map(|(label, tasks)| (
+                    label.clone(),
+                    tasks.iter().map(|x| x.id).collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some(title(date(10, 15))), vec![2]),
+                (Some(title(date(10, 14))), vec![0, 3]),
+                (Some(title(date(9, 30))), vec![1]),
+                (Some("No completion date".to_string()), vec![4]),
+            ]
+        );
+        assert_eq!(
+            Group::Day.summary(&groups[1].1).as_deref(),
+            Some("2 tasks · +work 2, +home 1")
+        );
+        assert_eq!(Group::Day.summary(&groups[3].1).as_deref(), Some("1 task"));
+        assert_eq!(Group::Project.summary(&groups[1].1), None);
+
+        let sort = "file,file,month".parse::<Sort>().unwrap();
+        assert_eq!(
+            sort.apply(tasks, today)
+                .into_iter()
+                .map(|(label, tasks)| (label.unwrap(), tasks.len()))
+                .collect::<Vec<_>>(),
+            vec![
+                (crate::date::format_with(date(10, 1), "%B %Y").unwrap(), 3),
+                (crate::date::format_with(date(9, 1), "%B %Y").unwrap(), 1),
+                ("No completion date".to_string(), 1),
+            ]
+        );
+    }
+}
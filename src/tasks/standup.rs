@@ -0,0 +1,154 @@
+use chrono::Datelike as _;
+
+pub const TEMPLATE: &str = "# Standup {date}
+
+## Yesterday
+
+{yesterday}
+
+## Today
+
+{today}
+
+## Blockers
+
+{blockers}
+";
+
+#[derive(Clone, Debug)]
+pub struct Standup {
+    pub date: chrono::NaiveDate,
+    pub yesterday: Vec<super::Task>,
+    pub today: Vec<super::Task>,
+    pub blockers: Vec<super::Task>,
+}
+
+impl Standup {
+    pub fn new(tasks: &[super::Task], today: chrono::NaiveDate, blocker: &str) -> Self {
+        let since = last_workday(today);
+        let pending = tasks
+            .iter()
+            .filter(|x| !x.finished && x.threshold_date.is_none_or(|x| x <= today));
+
+        Self {
+            date: today,
+            yesterday: tasks
+                .iter()
+                .filter(|x| x.finished && x.finish_date.is_some_and(|x| x >= since))
+                .cloned()
+                .collect(),
+            today: pending
+                .clone()
+                .filter(|x| !Self::blocked(x, blocker))
+                .filter(|x| x.flagged || x.due_date.is_some_and(|x| x <= today))
+                .cloned()
+                .collect(),
+            blockers: pending
+                .filter(|x| Self::blocked(x, blocker))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn blocked(task: &super::Task, keyword: &str) -> bool {
+        let keyword = keyword.trim();
+
+        !keyword.is_empty()
+            && (task.projects.iter().any(|x| x == keyword)
+                || task.contexts.iter().any(|x| x == keyword)
+                || task.hashtags.iter().any(|x| x == keyword)
+                || task.tags.contains_key(keyword))
+    }
+
+    pub fn render(&self, template: &str) -> String {
+        template
+            .replace("{date}", &crate::date::format(self.date))
+            .replace("{yesterday}", &Self::list(&self.yesterday))
+            .replace("{today}", &Self::list(&self.today))
+            .replace("{blockers}", &Self::list(&self.blockers))
+    }
+
+    fn list(tasks: &[super::Task]) -> String {
+        if tasks.is_empty() {
+            return format!("- {}", crate::i18n::gettext("Nothing"));
+        }
+
+        tasks
+            .iter()
+            .map(|x| format!("- {}", x.subject))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+pub fn last_workday(today: chrono::NaiveDate) -> chrono::NaiveDate {
+    let mut date = today.pred_opt().unwrap_or(today);
+
+    while matches!(date.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun) {
+        date = date.pred_opt().unwrap_or(date);
+    }
+
+    date
+}
+
+pub fn template_path() -> std::path::PathBuf {
+    crate::settings::config_file("standup.md")
+}
+
+pub fn template() -> String {
+    std::fs::read_to_string(template_path()).unwrap_or_else(|_| TEMPLATE.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::standup::*;
+
+    #[test]
+    fn last_workday() {
+        let date = |d| chrono::NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+
+        assert_eq!(super::last_workday(date(15)), date(14));
+        assert_eq!(super::last_workday(date(19)), date(16));
+        assert_eq!(super::last_workday(date(18)), date(16));
+    }
+
+    #[test]
+    fn render() {
+        let date = |d| chrono::NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let mut tasks = [
+            "x 2026-10-16 write report +work",
+            "x 2026-10-15 old stuff",
+            "x 2026-10-19 call bob",
+            "pay bills due:2026-10-19",
+            "buy milk due:2026-10-25",
+            "review PR @blocked",
+            "deploy +work",
+            "water plants",
+        ]
+        .iter()
+        .map(|x| x.parse::<crate::tasks::Task>().unwrap())
+        .collect::<Vec<_>>();
+        tasks[7].flagged = true;
+        tasks[6]
+            .tags
+            .insert("blocked".to_string(), "ops".to_string());
+
+        let standup = Standup::new(&tasks, date(19), "blocked");
+
+        assert_eq!(
+            standup.render("{yesterday}\n--\n{today}\n--\n{blockers}"),
+            "- write report +work
+- call bob
+--
+- pay bills
+- water plants
+--
+- review PR @blocked
+- deploy +work"
+        );
+        assert_eq!(
+            Standup::new(&tasks, date(19), "").render("{blockers}"),
+            "- Nothing"
+        );
+    }
+}
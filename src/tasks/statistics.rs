@@ -0,0 +1,392 @@
+use chrono::Datelike as _;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Period {
+    Day,
+    #[default]
+    Week,
+    Month,
+}
+
+impl Period {
+    pub const ALL: [Self; 3] = [Self::Day, Self::Week, Self::Month];
+
+    pub fn label(&self) -> String {
+        use crate::i18n::gettext;
+
+        match self {
+            Self::Day => gettext("Per day"),
+            Self::Week => gettext("Per week"),
+            Self::Month => gettext("Per month"),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    pub fn start(&self, date: chrono::NaiveDate, week_start: chrono::Weekday) -> chrono::NaiveDate {
+        match self {
+            Self::Day => date,
+            Self::Week => crate::date::end_of_week(date, week_start) - chrono::Duration::days(6),
+            Self::Month => date.with_day(1).unwrap(),
+        }
+    }
+
+    pub fn next(&self, start: chrono::NaiveDate) -> chrono::NaiveDate {
+        match self {
+            Self::Day => start + chrono::Duration::days(1),
+            Self::Week => start + chrono::Duration::days(7),
+            Self::Month => start + chrono::Months::new(1),
+        }
+    }
+
+    pub fn format(&self, start: chrono::NaiveDate) -> String {
+        let format = match self {
+            Self::Day | Self::Week => "%d %b",
+            Self::Month => "%b %Y",
+        };
+
+        crate::date::format_with(start, format).unwrap_or_else(|| start.to_string())
+    }
+}
+
+impl std::str::FromStr for Period {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|x| x.name() == s)
+            .ok_or_else(|| format!("Invalid period '{s}'"))
+    }
+}
+
+impl std::fmt::Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bucket {
+    pub start: chrono::NaiveDate,
+    pub created: usize,
+    pub completed: usize,
+    pub overdue: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Breakdown {
+    pub name: String,
+    pub created: usize,
+    pub completed: usize,
+    pub cycle_time: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Statistics {
+    pub buckets: Vec<Bucket>,
+    pub created: usize,
+    pub completed: usize,
+    pub cycle_time: Option<f64>,
+    pub projects: Vec<Breakdown>,
+    pub contexts: Vec<Breakdown>,
+}
+
+impl Statistics {
+    pub fn new(
+        tasks: &[super::Task],
+        (from, to): (chrono::NaiveDate, chrono::NaiveDate),
+        period: Period,
+        week_start: chrono::Weekday,
+    ) -> Self {
+        let created = tasks
+            .iter()
+            .filter(|x| Self::created_between(x, from, to))
+            .collect::<Vec<_>>();
+        let completed = tasks
+            .iter()
+            .filter(|x| Self::completed_between(x, from, to))
+            .collect::<Vec<_>>();
+
+        let mut buckets = Vec::new();
+        let mut start = period.start(from, week_start);
+
+        while start <= to {
+            let next = period.next(start);
+            let first = start.max(from);
+            let last = next.pred_opt().unwrap().min(to);
+
+            buckets.push(Bucket {
+                start,
+                created: created
+                    .iter()
+                    .filter(|x| Self::created_between(x, first, last))
+                    .count(),
+                completed: completed
+                    .iter()
+                    .filter(|x| Self::completed_between(x, first, last))
+                    .count(),
+                overdue: tasks.iter().filter(|x| Self::overdue(x, last)).count(),
+            });
+
+            start = next;
+        }
+
+        Self {
+            buckets,
+            created: created.len(),
+            completed: completed.len(),
+            cycle_time: Self::cycle_time(&completed),
+            projects: Self::breakdown(&created, &completed, |x| &x.projects),
+            contexts: Self::breakdown(&created, &completed, |x| &x.contexts),
+        }
+    }
+
+    fn created_between(task: &super::Task, from: chrono::NaiveDate, to: chrono::NaiveDate) -> bool {
+        task.create_date.is_some_and(|x| x >= from && x <= to)
+    }
+
+    fn completed_between(
+        task: &super::Task,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> bool {
+        task.finished && task.finish_date.is_some_and(|x| x >= from && x <= to)
+    }
+
+    fn overdue(task: &super::Task, date: chrono::NaiveDate) -> bool {
+        let open = match (task.finished, task.finish_date) {
+            (false, _) => true,
+            (true, Some(finish_date)) => finish_date > date,
+            (true, None) => false,
+        };
+
+        open && task.due_date.is_some_and(|x| x < date)
+            && task.create_date.is_none_or(|x| x <= date)
+    }
+
+    fn cycle_time(tasks: &[&super::Task]) -> Option<f64> {
+        let days = tasks
+            .iter()
+            .filter_map(|x| Some((x.finish_date? - x.create_date?).num_days()))
+            .collect::<Vec<_>>();
+
+        if days.is_empty() {
+            None
+        } else {
+            Some(days.iter().sum::<i64>() as f64 / days.len() as f64)
+        }
+    }
+
+    fn breakdown(
+        created: &[&super::Task],
+        completed: &[&super::Task],
+        tags: fn(&super::Task) -> &Vec<String>,
+    ) -> Vec<Breakdown> {
+        let mut names = created
+            .iter()
+            .chain(completed)
+            .flat_map(|x| tags(x).clone())
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+
+        let mut breakdown = names
+            .into_iter()
+            .map(|name| {
+                let completed = completed
+                    .iter()
+                    .filter(|x| tags(x).contains(&name))
+                    .copied()
+                    .collect::<Vec<_>>();
+
+                Breakdown {
+                    created: created.iter().filter(|x| tags(x).contains(&name)).count(),
+                    completed: completed.len(),
+                    cycle_time: Self::cycle_time(&completed),
+                    name,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        breakdown.sort_by(|a, b| b.completed.cmp(&a.completed).then(a.name.cmp(&b.name)));
+
+        breakdown
+    }
+}
+
+pub fn completions(tasks: &[super::Task]) -> std::collections::BTreeMap<chrono::NaiveDate, usize> {
+    let mut completions = std::collections::BTreeMap::new();
+
+    for finish_date in tasks
+        .iter()
+        .filter(|x| x.finished)
+        .filter_map(|x| x.finish_date)
+    {
+        *completions.entry(finish_date).or_default() += 1;
+    }
+
+    completions
+}
+
+pub fn streaks(
+    completions: &std::collections::BTreeMap<chrono::NaiveDate, usize>,
+    today: chrono::NaiveDate,
+) -> (usize, usize) {
+    let mut longest = 0;
+    let mut length = 0;
+    let mut previous = None::<chrono::NaiveDate>;
+
+    for date in completions.keys().copied() {
+        if previous.and_then(|x| x.succ_opt()) == Some(date) {
+            length += 1;
+        } else {
+            length = 1;
+        }
+
+        longest = longest.max(length);
+        previous = Some(date);
+    }
+
+    let mut date = if completions.contains_key(&today) {
+        Some(today)
+    } else {
+        today.pred_opt()
+    };
+    let mut current = 0;
+
+    while let Some(day) = date.filter(|x| completions.contains_key(x)) {
+        current += 1;
+        date = day.pred_opt();
+    }
+
+    (current, longest)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::statistics::*;
+
+    #[test]
+    fn period() {
+        let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let thursday = date(2026, 10, 15);
+
+        assert_eq!(Period::Day.start(thursday, chrono::Weekday::Mon), thursday);
+        assert_eq!(
+            Period::Week.start(thursday, chrono::Weekday::Mon),
+            date(2026, 10, 12)
+        );
+        assert_eq!(
+            Period::Week.start(thursday, chrono::Weekday::Sun),
+            date(2026, 10, 11)
+        );
+        assert_eq!(
+            Period::Month.start(thursday, chrono::Weekday::Mon),
+            date(2026, 10, 1)
+        );
+        assert_eq!(Period::Month.next(date(2026, 12, 1)), date(2027, 1, 1));
+        assert_eq!("month".parse(), Ok(Period::Month));
+    }
+
+    #[test]
+    fn statistics() {
+        let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let tasks = [
+            "x 2026-10-06 2026-10-01 write report +work @office",
+            "x 2026-10-13 2026-10-05 call bob +work @phone",
+            "x 2026-10-14 2026-10-12 buy milk +home",
+            "2026-10-02 pay bills +home due:2026-10-09",
+            "2026-10-13 read a book",
+            "x 2026-10-08 2026-10-01 late report +work due:2026-10-05",
+        ]
+        .iter()
+        .map(|x| x.parse::<crate::tasks::Task>().unwrap())
+        .collect::<Vec<_>>();
+
+        let statistics = Statistics::new(
+            &tasks,
+            (date(2026, 10, 5), date(2026, 10, 15)),
+            Period::Week,
+            chrono::Weekday::Mon,
+        );
+
+        assert_eq!(
+            statistics.buckets,
+            [
+                Bucket {
+                    start: date(2026, 10, 5),
+                    created: 1,
+                    completed: 2,
+                    overdue: 1,
+                },
+                Bucket {
+                    start: date(2026, 10, 12),
+                    created: 2,
+                    completed: 2,
+                    overdue: 1,
+                },
+            ]
+        );
+        assert_eq!(statistics.created, 3);
+        assert_eq!(statistics.completed, 4);
+        assert_eq!(statistics.cycle_time, Some(5.5));
+
+        assert_eq!(
+            statistics.projects,
+            [
+                Breakdown {
+                    name: "work".to_string(),
+                    created: 1,
+                    completed: 3,
+                    cycle_time: Some(20. / 3.),
+                },
+                Breakdown {
+                    name: "home".to_string(),
+                    created: 1,
+                    completed: 1,
+                    cycle_time: Some(2.),
+                },
+            ]
+        );
+        assert_eq!(statistics.contexts.len(), 2);
+    }
+
+    #[test]
+    fn streaks() {
+        let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let tasks = [
+            "x 2026-10-01 first",
+            "x 2026-10-02 second",
+            "x 2026-10-03 third",
+            "x 2026-10-03 fourth",
+            "x 2026-10-08 fifth",
+            "x 2026-10-09 sixth",
+            "x sixth without date",
+            "not done",
+        ]
+        .iter()
+        .map(|x| x.parse::<crate::tasks::Task>().unwrap())
+        .collect::<Vec<_>>();
+
+        let completions = completions(&tasks);
+        assert_eq!(completions.len(), 5);
+        assert_eq!(completions.get(&date(2026, 10, 3)), Some(&2));
+
+        assert_eq!(super::streaks(&completions, date(2026, 10, 9)), (2, 3));
+        assert_eq!(super::streaks(&completions, date(2026, 10, 10)), (2, 3));
+        assert_eq!(super::streaks(&completions, date(2026, 10, 11)), (0, 3));
+        assert_eq!(
+            super::streaks(&Default::default(), date(2026, 10, 11)),
+            (0, 0)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::i18n::gettext;
+
 #[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Task {
     inner: todo_txt::task::Extended,
@@ -33,6 +35,68 @@ impl Task {
         subject
     }
 
+    pub fn links(&self) -> Vec<String> {
+        let regex = regex::Regex::new(r"(?P<url>[\w]+://[^\s]+)").unwrap();
+
+        regex
+            .find_iter(&self.subject)
+            .map(|x| x.as_str().to_string())
+            .collect()
+    }
+
+    pub fn status(&self, today: chrono::NaiveDate) -> Vec<String> {
+        let mut status = Vec::new();
+
+        if self.finished {
+            status.push(gettext("completed"));
+        }
+
+        if let Some(due_date) = self.due_date {
+            if !self.finished && due_date < today {
+                status.push(gettext("overdue"));
+            }
+
+            status.push(
+                gettext("due {date}").replace("{date}", &crate::date::alias(due_date, today)),
+            );
+        }
+
+        if self.flagged {
+            status.push(gettext("flagged"));
+        }
+
+        if self.recurrence.is_some() {
+            status.push(gettext("recurring"));
+        }
+
+        status
+    }
+
+    pub fn description(&self, today: chrono::NaiveDate) -> String {
+        let mut description = Vec::new();
+
+        if !self.priority.is_lowest() {
+            let priority = (b'A' + u8::from(self.priority.clone())) as char;
+            description
+                .push(gettext("priority {priority}").replace("{priority}", &priority.to_string()));
+        }
+
+        description.extend(self.status(today));
+
+        if let Some(threshold_date) = self.threshold_date {
+            description.push(
+                gettext("deferred until {date}")
+                    .replace("{date}", &crate::date::alias(threshold_date, today)),
+            );
+        }
+
+        if self.has_note() {
+            description.push(gettext("has a note"));
+        }
+
+        description.join(", ")
+    }
+
     fn markup_escape(text: &str) -> String {
         gtk::glib::markup_escape_text(text).as_str().to_string()
     }
@@ -102,4 +166,37 @@ mod tests {
             "P&amp;T keep focus on long term <b>+HoWE</b>"
         );
     }
+
+    #[test]
+    fn description() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+        let mut task = "(A) Call Mom due:2026-10-16".parse::<Task>().unwrap();
+        task.flagged = true;
+        task.recurrence = Some(todo_txt::task::Recurrence {
+            num: 1,
+            period: todo_txt::task::Period::Week,
+            strict: false,
+        });
+        assert_eq!(
+            task.description(today),
+            "priority A, due tomorrow, flagged, recurring"
+        );
+        assert_eq!(task.status(today), ["due tomorrow", "flagged", "recurring"]);
+
+        let task = "Pay rent due:2026-10-14 t:2026-10-15"
+            .parse::<Task>()
+            .unwrap();
+        assert_eq!(
+            task.description(today),
+            "overdue, due yesterday, deferred until today"
+        );
+
+        let task = "x 2026-10-14 Pay rent due:2026-10-14"
+            .parse::<Task>()
+            .unwrap();
+        assert_eq!(task.description(today), "completed, due yesterday");
+
+        assert_eq!(Task::new().description(today), "");
+    }
 }
@@ -0,0 +1,81 @@
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Visibility {
+    pub defered: bool,
+    pub done: bool,
+    pub hidden: bool,
+}
+
+impl Visibility {
+    pub const ALL: Self = Self {
+        defered: true,
+        done: true,
+        hidden: true,
+    };
+
+    pub fn matches(&self, task: &super::Task, today: chrono::NaiveDate) -> bool {
+        (self.done || !task.finished)
+            && (self.hidden || !task.hidden)
+            && (self.defered || task.threshold_date.is_none_or(|x| x <= today))
+    }
+}
+
+impl std::str::FromStr for Visibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut visibility = Self::default();
+
+        for flag in s.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+            match flag {
+                "defered" => visibility.defered = true,
+                "done" => visibility.done = true,
+                "hidden" => visibility.hidden = true,
+                _ => return Err(format!("Invalid visibility '{flag}'")),
+            }
+        }
+
+        Ok(visibility)
+    }
+}
+
+impl std::fmt::Display for Visibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flags = [
+            ("defered", self.defered),
+            ("done", self.done),
+            ("hidden", self.hidden),
+        ]
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+
+        f.write_str(&flags.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tasks::visibility::*;
+
+    #[test]
+    fn matches() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let task = |line: &str| line.parse::<crate::tasks::Task>().unwrap();
+
+        let visibility = Visibility::default();
+        assert!(visibility.matches(&task("call bob t:2026-10-15"), today));
+        assert!(!visibility.matches(&task("x 2026-10-14 call bob"), today));
+        assert!(!visibility.matches(&task("call bob t:2026-10-16"), today));
+        assert!(!visibility.matches(&task("call bob h:1"), today));
+
+        let visibility = "defered,hidden".parse::<Visibility>().unwrap();
+        assert_eq!(visibility.to_string(), "defered,hidden");
+        assert!(visibility.matches(&task("call bob t:2026-10-16 h:1"), today));
+        assert!(!visibility.matches(&task("x 2026-10-14 call bob"), today));
+
+        assert!(Visibility::ALL.matches(&task("x 2026-10-14 call bob t:2026-10-16 h:1"), today));
+        assert_eq!("".parse::<Visibility>(), Ok(Visibility::default()));
+        assert!("archived".parse::<Visibility>().is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use gtk::prelude::*;
+
+use crate::i18n::gettext;
+
+#[derive(Clone, Debug)]
+pub struct Toast {
+    pub text: String,
+    pub level: log::Level,
+    pub details: Option<String>,
+    pub actions: Vec<(String, crate::application::Msg)>,
+}
+
+impl Toast {
+    pub fn info(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            level: log::Level::Info,
+            details: None,
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn error(text: &str) -> Self {
+        Self {
+            level: log::Level::Error,
+            ..Self::info(text)
+        }
+    }
+
+    pub fn with_details(mut self, details: &str) -> Self {
+        self.details = Some(details.to_string());
+        self
+    }
+
+    pub fn with_action(mut self, label: &str, msg: crate::application::Msg) -> Self {
+        self.actions.push((label.to_string(), msg));
+        self
+    }
+}
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Action(usize),
+    Dismiss,
+    Show(Box<Toast>),
+    Timeout(u32),
+}
+
+pub struct Model {
+    generation: u32,
+    toast: Option<Toast>,
+}
+
+impl Model {
+    const TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+    fn show(
+        &mut self,
+        widgets: &ModelWidgets,
+        root: &gtk::Revealer,
+        sender: relm4::ComponentSender<Self>,
+        toast: Toast,
+    ) {
+        use relm4::RelmRemoveAllExt as _;
+
+        self.generation = self.generation.wrapping_add(1);
+
+        widgets.content.set_css_classes(&["toast"]);
+        widgets
+            .content
+            .add_css_class(&toast.level.to_string().to_lowercase());
+        widgets.text.set_text(&toast.text);
+        widgets.details.set_visible(toast.details.is_some());
+        widgets.details.set_expanded(false);
+        widgets
+            .details_text
+            .set_text(toast.details.as_deref().unwrap_or_default());
+
+        widgets.actions.remove_all();
+
+        for (n, (label, _)) in toast.actions.iter().enumerate() {
+            let button = gtk::Button::with_label(label);
+            let sender = sender.clone();
+            button.connect_clicked(move |_| sender.input(MsgInput::Action(n)));
+            widgets.actions.append(&button);
+        }
+
+        if toast.level > log::Level::Warn {
+            let generation = self.generation;
+
+            gtk::glib::timeout_add_local_once(Self::TIMEOUT, move || {
+                sender.input(MsgInput::Timeout(generation));
+            });
+        }
+
+        self.toast = Some(toast);
+        root.set_reveal_child(true);
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = ();
+    type Input = MsgInput;
+    type Output = crate::application::Msg;
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let model = Self {
+            generation: 0,
+            toast: None,
+        };
+
+        let widgets = view_output!();
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        match msg {
+            MsgInput::Action(n) => {
+                if let Some((_, msg)) = self.toast.take().and_then(|x| x.actions.get(n).cloned()) {
+                    sender.output(msg).ok();
+                }
+
+                root.set_reveal_child(false);
+            }
+            MsgInput::Dismiss => {
+                self.toast = None;
+                root.set_reveal_child(false);
+            }
+            MsgInput::Show(toast) => self.show(widgets, root, sender, *toast),
+            MsgInput::Timeout(generation) => {
+                if generation == self.generation && !widgets.details.is_expanded() {
+                    self.toast = None;
+                    root.set_reveal_child(false);
+                }
+            }
+        }
+    }
+
+    view! {
+        gtk::Revealer {
+            set_halign: gtk::Align::Center,
+            set_margin_bottom: 20,
+            set_transition_type: gtk::RevealerTransitionType::SlideUp,
+            set_valign: gtk::Align::End,
+
+            #[name = "content"]
+            gtk::Box {
+                add_css_class: "toast",
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+                update_property: &[gtk::accessible::Property::Label(&gettext("Notification"))],
+
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Horizontal,
+                    set_spacing: 10,
+
+                    #[name = "text"]
+                    gtk::Label {
+                        set_hexpand: true,
+                        set_xalign: 0.,
+                    },
+                    #[name = "actions"]
+                    gtk::Box {
+                        set_orientation: gtk::Orientation::Horizontal,
+                        set_spacing: 5,
+                    },
+                    gtk::Button {
+                        add_css_class: "flat",
+                        set_icon_name: "window-close",
+                        set_tooltip_text: Some(&gettext("Dismiss")),
+
+                        connect_clicked => MsgInput::Dismiss,
+                    },
+                },
+                #[name = "details"]
+                gtk::Expander {
+                    add_css_class: "details",
+                    set_label: Some(&gettext("Details")),
+                    set_visible: false,
+
+                    #[wrap(Some)]
+                    #[name = "details_text"]
+                    set_child = &gtk::Label {
+                        set_selectable: true,
+                        set_wrap: true,
+                        set_xalign: 0.,
+                    },
+                },
+            },
+        }
+    }
+}
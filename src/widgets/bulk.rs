@@ -0,0 +1,281 @@
+use gtk::prelude::*;
+
+use crate::i18n::{gettext, ngettext};
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Apply(crate::tasks::Change),
+    Date(crate::edit::DateType),
+    Select(usize),
+    Tag(bool),
+}
+
+#[derive(Debug)]
+pub enum MsgOutput {
+    Apply(crate::tasks::Change),
+    Unselect,
+}
+
+pub struct Model;
+
+impl Model {
+    fn date(
+        &self,
+        widgets: &ModelWidgets,
+        sender: relm4::ComponentSender<Self>,
+        date_type: crate::edit::DateType,
+    ) {
+        let entry = match date_type {
+            crate::edit::DateType::Due => &widgets.due_entry,
+            crate::edit::DateType::Threshold => &widgets.threshold_entry,
+            crate::edit::DateType::Finish => return,
+        };
+
+        let text = entry.text();
+        let date = if text.trim().is_empty() {
+            None
+        } else if let Some(date) = crate::date::parse(&text) {
+            Some(date)
+        } else {
+            entry.add_css_class("error");
+            return;
+        };
+
+        entry.remove_css_class("error");
+        entry.set_text("");
+
+        let change = match date_type {
+            crate::edit::DateType::Due => crate::tasks::Change::Due(date),
+            _ => crate::tasks::Change::Threshold(date),
+        };
+
+        sender.input(MsgInput::Apply(change));
+    }
+
+    fn tag(&self, widgets: &ModelWidgets, sender: relm4::ComponentSender<Self>, add: bool) {
+        let tag = widgets.tag_entry.text().trim().to_string();
+
+        if tag.is_empty() {
+            return;
+        }
+
+        widgets.tag_entry.set_text("");
+
+        let change = if add {
+            crate::tasks::Change::AddTag(tag)
+        } else {
+            crate::tasks::Change::RemoveTag(tag)
+        };
+
+        sender.input(MsgInput::Apply(change));
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = ();
+    type Input = MsgInput;
+    type Output = MsgOutput;
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let model = Self;
+
+        let widgets = view_output!();
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        use MsgInput::*;
+
+        match msg {
+            Apply(change) => {
+                widgets.flag.popdown();
+                widgets.priority.popdown();
+                widgets.due.popdown();
+                widgets.threshold.popdown();
+                widgets.tag.popdown();
+
+                sender.output(MsgOutput::Apply(change)).ok();
+            }
+            Date(date_type) => self.date(widgets, sender, date_type),
+            Select(count) => {
+                widgets.count.set_text(
+                    &ngettext("{count} selected", "{count} selected", count as u32)
+                        .replace("{count}", &count.to_string()),
+                );
+                root.set_revealed(count > 0);
+            }
+            Tag(add) => self.tag(widgets, sender, add),
+        }
+    }
+
+    view! {
+        gtk::ActionBar {
+            set_revealed: false,
+
+            pack_start = &gtk::Button {
+                set_icon_name: "edit-clear",
+                set_tooltip_text: Some(&gettext("Clear selection")),
+
+                connect_clicked[sender] => move |_| {
+                    sender.output(MsgOutput::Unselect).ok();
+                },
+            },
+            #[name = "count"]
+            pack_start = &gtk::Label {
+            },
+            pack_end = &gtk::Button {
+                set_icon_name: "object-select",
+                set_tooltip_text: Some(&gettext("Complete")),
+
+                connect_clicked => MsgInput::Apply(crate::tasks::Change::Complete),
+            },
+            #[name = "flag"]
+            pack_end = &gtk::MenuButton {
+                set_icon_name: "emblem-favorite",
+                set_tooltip_text: Some(&gettext("Flag")),
+
+                #[wrap(Some)]
+                set_popover = &gtk::Popover {
+                    gtk::Box {
+                        set_orientation: gtk::Orientation::Vertical,
+
+                        gtk::Button {
+                            set_label: &gettext("Flag"),
+                            connect_clicked => MsgInput::Apply(crate::tasks::Change::Flag(true)),
+                        },
+                        gtk::Button {
+                            set_label: &gettext("Unflag"),
+                            connect_clicked => MsgInput::Apply(crate::tasks::Change::Flag(false)),
+                        },
+                    },
+                },
+            },
+            #[name = "priority"]
+            pack_end = &gtk::MenuButton {
+                set_label: &gettext("Priority"),
+
+                #[wrap(Some)]
+                set_popover = &gtk::Popover {
+                    gtk::Box {
+                        set_orientation: gtk::Orientation::Horizontal,
+
+                        gtk::Button {
+                            set_label: "A",
+                            connect_clicked => MsgInput::Apply(crate::tasks::Change::Priority(0.into())),
+                        },
+                        gtk::Button {
+                            set_label: "B",
+                            connect_clicked => MsgInput::Apply(crate::tasks::Change::Priority(1.into())),
+                        },
+                        gtk::Button {
+                            set_label: "C",
+                            connect_clicked => MsgInput::Apply(crate::tasks::Change::Priority(2.into())),
+                        },
+                        gtk::Button {
+                            set_label: "D",
+                            connect_clicked => MsgInput::Apply(crate::tasks::Change::Priority(3.into())),
+                        },
+                        gtk::Button {
+                            set_label: "E",
+                            connect_clicked => MsgInput::Apply(crate::tasks::Change::Priority(4.into())),
+                        },
+                        gtk::Button {
+                            set_label: &gettext("None"),
+                            set_tooltip_text: Some(&gettext("No priority")),
+                            connect_clicked => MsgInput::Apply(crate::tasks::Change::Priority(26.into())),
+                        },
+                    },
+                },
+            },
+            #[name = "due"]
+            pack_end = &gtk::MenuButton {
+                set_label: &gettext("Due"),
+
+                #[wrap(Some)]
+                set_popover = &gtk::Popover {
+                    gtk::Box {
+                        set_orientation: gtk::Orientation::Horizontal,
+
+                        #[name = "due_entry"]
+                        gtk::Entry {
+                            set_placeholder_text: Some(&gettext("tomorrow, next fri, +2d…")),
+                            set_tooltip_text: Some(&gettext("Leave empty to clear the due date")),
+
+                            connect_activate => MsgInput::Date(crate::edit::DateType::Due),
+                        },
+                        gtk::Button {
+                            set_icon_name: "object-select",
+                            set_tooltip_text: Some(&gettext("Apply")),
+                            connect_clicked => MsgInput::Date(crate::edit::DateType::Due),
+                        },
+                    },
+                },
+            },
+            #[name = "threshold"]
+            pack_end = &gtk::MenuButton {
+                set_label: &gettext("Defer"),
+
+                #[wrap(Some)]
+                set_popover = &gtk::Popover {
+                    gtk::Box {
+                        set_orientation: gtk::Orientation::Horizontal,
+
+                        #[name = "threshold_entry"]
+                        gtk::Entry {
+                            set_placeholder_text: Some(&gettext("tomorrow, next fri, +2d…")),
+                            set_tooltip_text: Some(&gettext("Leave empty to clear the threshold date")),
+
+                            connect_activate => MsgInput::Date(crate::edit::DateType::Threshold),
+                        },
+                        gtk::Button {
+                            set_icon_name: "object-select",
+                            set_tooltip_text: Some(&gettext("Apply")),
+                            connect_clicked => MsgInput::Date(crate::edit::DateType::Threshold),
+                        },
+                    },
+                },
+            },
+            #[name = "tag"]
+            pack_end = &gtk::MenuButton {
+                set_label: &gettext("Tag"),
+
+                #[wrap(Some)]
+                set_popover = &gtk::Popover {
+                    gtk::Box {
+                        set_orientation: gtk::Orientation::Horizontal,
+
+                        #[name = "tag_entry"]
+                        gtk::Entry {
+                            set_placeholder_text: Some(&gettext("+project @context #tag key:value")),
+
+                            connect_activate => MsgInput::Tag(true),
+                        },
+                        gtk::Button {
+                            set_icon_name: "list-add",
+                            set_tooltip_text: Some(&gettext("Add")),
+                            connect_clicked => MsgInput::Tag(true),
+                        },
+                        gtk::Button {
+                            set_icon_name: "list-remove",
+                            set_tooltip_text: Some(&gettext("Remove")),
+                            connect_clicked => MsgInput::Tag(false),
+                        },
+                    },
+                },
+            },
+        }
+    }
+}
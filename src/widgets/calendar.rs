@@ -1,6 +1,8 @@
 use chrono::Datelike as _;
 use gtk::prelude::*;
 
+use crate::i18n::gettext;
+
 pub struct Model {
     date: Option<chrono::NaiveDate>,
     label: &'static str,
@@ -11,6 +13,7 @@ pub enum MsgInput {
     Add(todo_txt::task::Period),
     DateSelected(gtk::glib::DateTime),
     DateUpdated,
+    Parse(String),
     Set(Option<chrono::NaiveDate>),
 }
 
@@ -38,6 +41,33 @@ impl Model {
         sender.output(MsgOutput::Updated(self.date)).ok();
         widgets.popover.popdown();
     }
+
+    fn parse(&mut self, widgets: &ModelWidgets, text: &str) {
+        if text.trim().is_empty() {
+            self.date = None;
+            widgets.entry.remove_css_class("error");
+            widgets.preview.set_visible(false);
+            return;
+        }
+
+        match crate::date::parse(text) {
+            Some(date) => {
+                self.date = Some(date);
+                widgets.entry.remove_css_class("error");
+                widgets.preview.set_text(&format!(
+                    "{} {}",
+                    crate::date::format_with(date, "%A").unwrap_or_default(),
+                    crate::date::format(date)
+                ));
+            }
+            None => {
+                widgets.entry.add_css_class("error");
+                widgets.preview.set_text(&gettext("Unable to parse date"));
+            }
+        }
+
+        widgets.preview.set_visible(true);
+    }
 }
 
 #[relm4::component(pub)]
@@ -76,11 +106,13 @@ impl relm4::Component for Model {
         match msg {
             Add(period) => self.add(sender, period),
             DateSelected(date) => self.date_selected(widgets, sender, date),
+            Parse(text) => self.parse(widgets, &text),
             Set(date) => {
                 self.date = date;
 
-                if let Some(date) = self.date {
-                    widgets.entry.set_text(&date.format("%Y-%m-%d").to_string());
+                match self.date {
+                    Some(date) => widgets.entry.set_text(&crate::date::format_editable(date)),
+                    None => widgets.entry.set_text(""),
                 }
 
                 let date = self.date.unwrap_or_else(crate::date::today);
@@ -89,7 +121,9 @@ impl relm4::Component for Model {
                 widgets.calendar.set_month(date.month() as i32 - 1);
                 widgets.calendar.set_year(date.year());
             }
+            DateUpdated if widgets.entry.has_css_class("error") => (),
             DateUpdated => {
+                sender.input(MsgInput::Set(self.date));
                 sender.output(MsgOutput::Updated(self.date)).ok();
             }
         }
@@ -103,7 +137,7 @@ impl relm4::Component for Model {
 
             gtk::Label {
                 set_hexpand: true,
-                set_text: &model.label,
+                set_text: &gettext(model.label),
                 set_width_request: 200,
                 set_xalign: 1.,
                 set_yalign: 0.,
@@ -114,6 +148,7 @@ impl relm4::Component for Model {
                 gtk::Box {
                     gtk::MenuButton {
                         set_icon_name: "x-office-calendar",
+                        set_tooltip_text: Some(&gettext("Choose a date")),
                         #[wrap(Some)]
                         #[name = "popover"]
                         set_popover = &gtk::Popover {
@@ -128,13 +163,24 @@ impl relm4::Component for Model {
                     #[name = "entry"]
                     gtk::Entry {
                         set_hexpand: true,
+                        set_placeholder_text: Some(&gettext("tomorrow, next fri, +2d…")),
                         set_width_request: 214,
 
+                        connect_activate => MsgInput::DateUpdated,
+                        connect_changed[sender] => move |this| {
+                            sender.input(MsgInput::Parse(this.text().to_string()));
+                        },
                         connect_move_focus[sender] => move |_, _| {
                             sender.input(MsgInput::DateUpdated);
                         },
                     },
                 },
+                #[name = "preview"]
+                gtk::Label {
+                    add_css_class: "preview",
+                    set_visible: false,
+                    set_xalign: 1.,
+                },
                 #[name = "buttons"]
                 gtk::Box {
                     set_halign: gtk::Align::End,
@@ -142,25 +188,25 @@ impl relm4::Component for Model {
 
                     gtk::Button {
                         set_label: "+1y",
-                        set_tooltip_text: Some("Add one year"),
+                        set_tooltip_text: Some(&gettext("Add one year")),
 
                         connect_clicked => MsgInput::Add(todo_txt::task::Period::Year),
                     },
                     gtk::Button {
                         set_label: "+1m",
-                        set_tooltip_text: Some("Add one month"),
+                        set_tooltip_text: Some(&gettext("Add one month")),
 
                         connect_clicked => MsgInput::Add(todo_txt::task::Period::Month),
                     },
                     gtk::Button {
                         set_label: "+1w",
-                        set_tooltip_text: Some("Add one month"),
+                        set_tooltip_text: Some(&gettext("Add one week")),
 
                         connect_clicked => MsgInput::Add(todo_txt::task::Period::Week),
                     },
                     gtk::Button {
                         set_label: "+1d",
-                        set_tooltip_text: Some("Add one month"),
+                        set_tooltip_text: Some(&gettext("Add one day")),
 
                         connect_clicked => MsgInput::Add(todo_txt::task::Period::Day),
                     },
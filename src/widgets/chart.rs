@@ -0,0 +1,215 @@
+use gtk::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Kind {
+    #[default]
+    Bars,
+    Line,
+}
+
+#[derive(Clone, Debug)]
+pub struct Series {
+    pub label: String,
+    pub color: (f64, f64, f64),
+    pub start: usize,
+    pub values: Vec<f64>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Data {
+    pub kind: Kind,
+    pub labels: Vec<String>,
+    pub markers: Vec<(usize, String)>,
+    pub series: Vec<Series>,
+}
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Set(Data),
+}
+
+pub struct Model {
+    data: std::rc::Rc<std::cell::RefCell<Data>>,
+    drawing_area: gtk::DrawingArea,
+    title: &'static str,
+}
+
+impl Model {
+    const LEFT: f64 = 40.;
+    const RIGHT: f64 = 10.;
+    const TOP: f64 = 25.;
+    const BOTTOM: f64 = 20.;
+
+    fn draw(
+        data: &Data,
+        context: &gtk::cairo::Context,
+        width: f64,
+        height: f64,
+    ) -> Result<(), gtk::cairo::Error> {
+        let plot_width = width - Self::LEFT - Self::RIGHT;
+        let plot_height = height - Self::TOP - Self::BOTTOM;
+        let n = data.labels.len();
+
+        if n == 0 || plot_width <= 0. || plot_height <= 0. {
+            return Ok(());
+        }
+
+        let max = data
+            .series
+            .iter()
+            .flat_map(|x| x.values.iter().copied())
+            .fold(1., f64::max)
+            .ceil();
+        let y = |value: f64| Self::TOP + plot_height * (1. - value / max);
+        let slot = plot_width / n as f64;
+
+        context.set_font_size(10.);
+        context.set_line_width(1.);
+        context.set_source_rgb(0.5, 0.5, 0.5);
+
+        for value in [0., (max / 2.).round(), max] {
+            context.move_to(Self::LEFT, y(value));
+            context.line_to(width - Self::RIGHT, y(value));
+            context.move_to(5., y(value) + 4.);
+            context.show_text(&value.to_string())?;
+        }
+        context.stroke()?;
+
+        let step = (60. / slot).ceil().max(1.) as usize;
+
+        for (i, label) in data.labels.iter().enumerate().step_by(step) {
+            context.move_to(Self::LEFT + slot * i as f64, height - 5.);
+            context.show_text(label)?;
+        }
+
+        let mut legend = Self::LEFT;
+
+        for series in &data.series {
+            let (r, g, b) = series.color;
+
+            context.set_source_rgb(r, g, b);
+            context.rectangle(legend, 5., 10., 10.);
+            context.fill()?;
+
+            context.set_source_rgb(0.5, 0.5, 0.5);
+            context.move_to(legend + 15., 14.);
+            context.show_text(&series.label)?;
+
+            legend += 30. + context.text_extents(&series.label)?.x_advance();
+        }
+
+        match data.kind {
+            Kind::Bars => {
+                let bar = slot * 0.8 / data.series.len().max(1) as f64;
+
+                for (s, series) in data.series.iter().enumerate() {
+                    let (r, g, b) = series.color;
+                    context.set_source_rgb(r, g, b);
+
+                    for (i, value) in series.values.iter().enumerate() {
+                        let i = series.start + i;
+                        let x = Self::LEFT + slot * (i as f64 + 0.1) + bar * s as f64;
+
+                        context.rectangle(x, y(*value), bar, y(0.) - y(*value));
+                    }
+
+                    context.fill()?;
+                }
+            }
+            Kind::Line => {
+                context.set_line_width(2.);
+
+                for series in &data.series {
+                    let (r, g, b) = series.color;
+                    context.set_source_rgb(r, g, b);
+
+                    for (i, value) in series.values.iter().enumerate() {
+                        let i = series.start + i;
+                        context.line_to(Self::LEFT + slot * (i as f64 + 0.5), y(*value));
+                    }
+
+                    context.stroke()?;
+                }
+            }
+        }
+
+        context.set_line_width(1.);
+        context.set_source_rgb(1., 0.4, 0.5);
+
+        for (i, label) in &data.markers {
+            let x = Self::LEFT + slot * (*i as f64 + 0.5);
+
+            context.move_to(x, Self::TOP);
+            context.line_to(x, y(0.));
+            context.stroke()?;
+
+            context.move_to(x + 3., Self::TOP + 10.);
+            context.show_text(label)?;
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self) {
+        let data = self.data.borrow();
+        let description = data
+            .series
+            .iter()
+            .map(|x| format!("{}: {}", x.label, x.values.iter().sum::<f64>()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        self.drawing_area.update_property(&[
+            gtk::accessible::Property::Label(&crate::i18n::gettext(self.title)),
+            gtk::accessible::Property::Description(&description),
+        ]);
+    }
+}
+
+impl relm4::SimpleComponent for Model {
+    type Init = &'static str;
+    type Input = MsgInput;
+    type Output = ();
+    type Root = gtk::DrawingArea;
+    type Widgets = ();
+
+    fn init_root() -> Self::Root {
+        gtk::DrawingArea::builder()
+            .accessible_role(gtk::AccessibleRole::Img)
+            .build()
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        _: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        root.set_height_request(200);
+        root.set_hexpand(true);
+
+        let model = Self {
+            data: std::rc::Rc::new(std::cell::RefCell::new(Data::default())),
+            drawing_area: root.clone(),
+            title: init,
+        };
+
+        let data = model.data.clone();
+        root.set_draw_func(move |_, context, width, height| {
+            Self::draw(&data.borrow(), context, width.into(), height.into()).ok();
+        });
+
+        model.describe();
+
+        relm4::ComponentParts { model, widgets: () }
+    }
+
+    fn update(&mut self, msg: Self::Input, _: relm4::ComponentSender<Self>) {
+        match msg {
+            MsgInput::Set(data) => {
+                self.data.replace(data);
+                self.drawing_area.queue_draw();
+                self.describe();
+            }
+        }
+    }
+}
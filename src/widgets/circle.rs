@@ -1,6 +1,14 @@
 use gtk::prelude::*;
 
-pub struct Model {}
+#[derive(Debug)]
+pub enum MsgInput {
+    Set(Box<crate::tasks::Task>),
+}
+
+pub struct Model {
+    task: std::rc::Rc<std::cell::RefCell<crate::tasks::Task>>,
+    drawing_area: gtk::DrawingArea,
+}
 
 impl Model {
     fn draw(
@@ -78,17 +86,28 @@ impl Model {
 
         Ok(())
     }
+
+    fn describe(&self) {
+        let status = self.task.borrow().status(crate::date::today());
+
+        self.drawing_area
+            .update_property(&[gtk::accessible::Property::Label(&status.join(", "))]);
+        self.drawing_area
+            .update_state(&[gtk::accessible::State::Hidden(status.is_empty())]);
+    }
 }
 
 impl relm4::SimpleComponent for Model {
     type Init = crate::tasks::Task;
-    type Input = ();
+    type Input = MsgInput;
     type Output = ();
     type Root = gtk::DrawingArea;
     type Widgets = ();
 
     fn init_root() -> Self::Root {
-        gtk::DrawingArea::new()
+        gtk::DrawingArea::builder()
+            .accessible_role(gtk::AccessibleRole::Img)
+            .build()
     }
 
     fn init(
@@ -99,12 +118,73 @@ impl relm4::SimpleComponent for Model {
         root.set_height_request(60);
         root.set_width_request(60);
 
-        let model = Self {};
+        let model = Self {
+            task: std::rc::Rc::new(std::cell::RefCell::new(init)),
+            drawing_area: root.clone(),
+        };
 
+        let task = model.task.clone();
         root.set_draw_func(move |drawing_area, context, _w, _h| {
-            Self::draw(&init, drawing_area, context).ok();
+            Self::draw(&task.borrow(), drawing_area, context).ok();
         });
 
+        model.describe();
+
         relm4::ComponentParts { model, widgets: () }
     }
+
+    fn update(&mut self, msg: Self::Input, _: relm4::ComponentSender<Self>) {
+        match msg {
+            MsgInput::Set(task) => {
+                self.task.replace(*task);
+                self.drawing_area.queue_draw();
+                self.describe();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::widgets::circle::*;
+
+    #[gtk::test]
+    fn accessibility() {
+        use relm4::Component as _;
+        use relm4::ComponentController as _;
+
+        let tomorrow = crate::date::today().succ_opt().unwrap();
+        let mut task = format!("Call Mom due:{tomorrow}")
+            .parse::<crate::tasks::Task>()
+            .unwrap();
+        task.flagged = true;
+
+        let circle = Model::builder().launch(task).detach();
+        let root = circle.widget();
+
+        assert!(gtk::test_accessible_has_role(
+            root,
+            gtk::AccessibleRole::Img
+        ));
+        assert_eq!(
+            crate::widgets::check_property(
+                root,
+                gtk::AccessibleProperty::Label,
+                "due tomorrow, flagged"
+            ),
+            Ok(())
+        );
+
+        circle.emit(MsgInput::Set(Box::new(
+            "x 2026-10-14 Call Mom"
+                .parse::<crate::tasks::Task>()
+                .unwrap(),
+        )));
+        crate::widgets::flush();
+
+        assert_eq!(
+            crate::widgets::check_property(root, gtk::AccessibleProperty::Label, "completed"),
+            Ok(())
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use gtk::prelude::*;
 use relm4::ComponentController as _;
 
+use crate::i18n::{gettext, gettext_noop, ngettext};
+
 #[repr(u32)]
 enum Column {
     Title = 0,
@@ -23,27 +25,44 @@ impl From<Column> for i32 {
 
 #[derive(Debug)]
 pub enum MsgInput {
+    Burndown(Option<crate::tasks::burndown::Burndown>),
+    Drop(Vec<usize>, f64, f64, bool),
+    Position(i32),
+    Select(String),
     SelectionChange,
     UpdateFilters(Vec<(String, (u32, u32))>),
     UpdateTasks(Vec<crate::tasks::Task>),
+    Visibility(crate::tasks::Visibility),
 }
 
 #[derive(Debug)]
 pub enum MsgOutput {
+    Apply(Vec<usize>, crate::tasks::Change),
     Complete(Box<crate::tasks::Task>),
+    Drop(Vec<usize>, String, bool),
     Edit(Box<crate::tasks::Task>),
     Filters(Vec<String>),
+    Focus(Box<crate::tasks::Task>),
+    Visibility(crate::tasks::Visibility),
 }
 
 pub struct Model {
+    burndown: relm4::Controller<super::chart::Model>,
+    collapsed: Vec<String>,
     filters: std::collections::BTreeMap<gtk::TreePath, String>,
+    page: &'static str,
+    position: i32,
+    selected: Vec<String>,
+    sort: relm4::Controller<super::sort::Model>,
     tasks: relm4::Controller<super::tasks::Model>,
+    visibility: relm4::Controller<super::visibility::Model>,
 }
 
 impl Model {
     fn update_filters(&mut self, widgets: &ModelWidgets, filters: Vec<(String, (u32, u32))>) {
-        let selection = widgets.tree_view.selection();
-        let (paths, _) = selection.selected_rows();
+        if !self.filters.is_empty() {
+            (self.collapsed, self.selected) = self.state(widgets);
+        }
 
         self.filters.clear();
         widgets.store.clear();
@@ -55,11 +74,102 @@ impl Model {
 
         widgets.tree_view.expand_all();
 
-        for path in paths {
-            gtk::prelude::TreeViewExt::set_cursor(&widgets.tree_view, &path, None, false);
+        for (path, filter) in self.filters.iter().rev() {
+            if self.collapsed.contains(filter) {
+                widgets.tree_view.collapse_row(path);
+            }
+        }
+
+        let selection = widgets.tree_view.selection();
+
+        for (path, filter) in &self.filters {
+            if self.selected.contains(filter) {
+                selection.select_path(path);
+            }
         }
     }
 
+    fn state(&self, widgets: &ModelWidgets) -> (Vec<String>, Vec<String>) {
+        let selection = widgets.tree_view.selection();
+
+        let collapsed = self
+            .filters
+            .iter()
+            .filter(|(path, _)| {
+                widgets
+                    .store
+                    .iter(path)
+                    .is_some_and(|x| widgets.store.iter_has_child(&x))
+                    && !widgets.tree_view.row_expanded(path)
+            })
+            .map(|(_, filter)| filter.clone())
+            .collect();
+
+        let selected = self
+            .filters
+            .iter()
+            .filter(|(path, _)| selection.path_is_selected(path))
+            .map(|(_, filter)| filter.clone())
+            .collect();
+
+        (collapsed, selected)
+    }
+
+    fn select(&self, widgets: &ModelWidgets, filter: &str) {
+        let Some((path, _)) = self.filters.iter().find(|(_, x)| *x == filter) else {
+            return;
+        };
+
+        let selection = widgets.tree_view.selection();
+        selection.unselect_all();
+
+        widgets.tree_view.expand_to_path(path);
+        selection.select_path(path);
+        widgets
+            .tree_view
+            .scroll_to_cell(Some(path), None::<&gtk::TreeViewColumn>, false, 0., 0.);
+    }
+
+    fn describe(&self, widgets: &ModelWidgets, paths: &[gtk::TreePath]) {
+        let description = paths
+            .iter()
+            .filter_map(|path| {
+                let iter = widgets.store.iter(path)?;
+                let title = widgets
+                    .store
+                    .get_value(&iter, Column::Title.into())
+                    .get::<String>()
+                    .ok()?;
+                let progress = widgets
+                    .store
+                    .get_value(&iter, Column::Tooltip.into())
+                    .get::<String>()
+                    .ok()?;
+
+                Some(format!("{title}, {progress}"))
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        widgets
+            .tree_view
+            .update_property(&[gtk::accessible::Property::Description(&description)]);
+    }
+
+    fn label(page: &str) -> String {
+        match page {
+            "projects" => gettext("Projects"),
+            "contexts" => gettext("Contexts"),
+            _ => gettext("Tags"),
+        }
+    }
+
+    fn session(page: &str, key: &str) -> Vec<String> {
+        crate::settings::get::<String>("session", &format!("{page}-{key}"))
+            .map(|x| x.split_whitespace().map(ToString::to_string).collect())
+            .unwrap_or_default()
+    }
+
     fn append(
         &mut self,
         widgets: &ModelWidgets,
@@ -92,7 +202,9 @@ impl Model {
             .store
             .set_value(&row, Column::Progress.into(), &progress.to_value());
 
-        let tooltip = format!("{done}/{total}");
+        let tooltip = gettext("{done} of {total} done")
+            .replace("{done}", &done.to_string())
+            .replace("{total}", &total.to_string());
         widgets
             .store
             .set_value(&row, Column::Tooltip.into(), &tooltip.to_value());
@@ -103,10 +215,149 @@ impl Model {
         self.filters.insert(path, filter);
     }
 
+    fn update_burndown(
+        &self,
+        widgets: &ModelWidgets,
+        burndown: Option<crate::tasks::burndown::Burndown>,
+    ) {
+        use super::chart::{Data, Kind, Series};
+
+        widgets.burndown.set_visible(burndown.is_some());
+
+        let Some(burndown) = burndown else {
+            return;
+        };
+
+        let end = burndown.end();
+        let dates = burndown
+            .start
+            .iter_days()
+            .take_while(|x| *x <= end)
+            .collect::<Vec<_>>();
+        let today = burndown.remaining.len().saturating_sub(1);
+        let late = burndown.late();
+
+        self.burndown.emit(super::chart::MsgInput::Set(Data {
+            kind: Kind::Line,
+            labels: dates
+                .iter()
+                .map(|x| crate::tasks::statistics::Period::Day.format(*x))
+                .collect(),
+            markers: burndown
+                .due
+                .and_then(|due| dates.iter().position(|x| *x == due))
+                .map(|x| (x, gettext("Due")))
+                .into_iter()
+                .collect(),
+            series: vec![
+                Series {
+                    label: gettext("Remaining"),
+                    color: (0.27, 0.6, 0.91),
+                    start: 0,
+                    values: burndown.remaining.iter().map(|x| *x as f64).collect(),
+                },
+                Series {
+                    label: gettext("Forecast"),
+                    color: if late {
+                        (1., 0.4, 0.5)
+                    } else {
+                        (0.45, 0.75, 0.3)
+                    },
+                    start: today,
+                    values: dates[today..]
+                        .iter()
+                        .map(|x| burndown.projection(*x))
+                        .collect(),
+                },
+            ],
+        }));
+
+        let left = burndown.left();
+        let mut summary = match burndown.forecast {
+            _ if left == 0 => gettext("All tasks done"),
+            Some(forecast) => ngettext(
+                "{count} task left, forecast completion on {date}",
+                "{count} tasks left, forecast completion on {date}",
+                left as u32,
+            )
+            .replace("{count}", &left.to_string())
+            .replace("{date}", &crate::date::format(forecast)),
+            None => ngettext(
+                "{count} task left, nothing completed in the last 4 weeks to forecast",
+                "{count} tasks left, nothing completed in the last 4 weeks to forecast",
+                left as u32,
+            )
+            .replace("{count}", &left.to_string()),
+        };
+
+        if late && let Some(due) = burndown.due {
+            summary.push_str(" · ");
+            summary.push_str(
+                &gettext("late for the due date {date}")
+                    .replace("{date}", &crate::date::format(due)),
+            );
+            widgets.burndown_summary.add_css_class("error");
+        } else {
+            widgets.burndown_summary.remove_css_class("error");
+        }
+
+        widgets.burndown_summary.set_text(&summary);
+    }
+
     fn update_tasks(&self, tasks: Vec<crate::tasks::Task>) {
         self.tasks.emit(super::tasks::MsgInput::Update(tasks));
     }
 
+    fn drop_target(sender: relm4::ComponentSender<Self>) -> gtk::DropTarget {
+        let drop_target = gtk::DropTarget::new(
+            String::static_type(),
+            gtk::gdk::DragAction::COPY | gtk::gdk::DragAction::MOVE,
+        );
+
+        drop_target.connect_drop(move |drop_target, value, x, y| {
+            let Ok(ids) = value.get::<String>() else {
+                return false;
+            };
+
+            let Ok(ids) = ids
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<Vec<_>, _>>()
+            else {
+                return false;
+            };
+
+            let replace = drop_target
+                .current_drop()
+                .is_some_and(|x| x.actions() == gtk::gdk::DragAction::MOVE);
+
+            sender.input(MsgInput::Drop(ids, x, y, replace));
+
+            true
+        });
+
+        drop_target
+    }
+
+    fn drop_tasks(
+        &self,
+        widgets: &ModelWidgets,
+        sender: relm4::ComponentSender<Self>,
+        ids: Vec<usize>,
+        (x, y): (f64, f64),
+        replace: bool,
+    ) {
+        let Some((Some(path), _)) = widgets.tree_view.dest_row_at_pos(x as i32, y as i32) else {
+            return;
+        };
+
+        if let Some(filter) = self.filters.get(&path) {
+            sender
+                .output(MsgOutput::Drop(ids, filter.clone(), replace))
+                .ok();
+        }
+    }
+
     fn select_range(treeview: &gtk::TreeView, path: &gtk::TreePath) {
         let model = treeview.model().unwrap();
 
@@ -129,22 +380,36 @@ impl Model {
 #[relm4::component(pub)]
 impl relm4::Component for Model {
     type CommandOutput = ();
-    type Init = ();
+    type Init = &'static str;
     type Input = MsgInput;
     type Output = MsgOutput;
 
     fn init(
-        _: Self::Init,
+        init: Self::Init,
         root: Self::Root,
         sender: relm4::ComponentSender<Self>,
     ) -> relm4::ComponentParts<Self> {
         let tasks = crate::widgets::tasks::Model::builder()
             .launch(().into())
             .forward(sender.output_sender(), |output| match output {
+                super::task::MsgOutput::Apply(ids, change) => MsgOutput::Apply(ids, change),
                 super::task::MsgOutput::Complete(task) => MsgOutput::Complete(task),
                 super::task::MsgOutput::Edit(task) => MsgOutput::Edit(task),
+                super::task::MsgOutput::Focus(task) => MsgOutput::Focus(task),
             });
 
+        let sort = super::sort::Model::builder()
+            .launch((init, crate::tasks::Sort::default()))
+            .forward(tasks.sender(), super::tasks::MsgInput::Sort);
+
+        let visibility = super::visibility::Model::builder()
+            .launch((init, crate::tasks::Visibility::default()))
+            .forward(sender.input_sender(), MsgInput::Visibility);
+
+        let burndown = super::chart::Model::builder()
+            .launch(gettext_noop("Project burndown"))
+            .detach();
+
         let columns = vec![
             gtk::glib::types::Type::STRING,
             gtk::glib::types::Type::STRING,
@@ -153,12 +418,23 @@ impl relm4::Component for Model {
         ];
 
         let model = Self {
-            tasks,
+            burndown,
+            collapsed: Self::session(init, "collapsed"),
             filters: std::collections::BTreeMap::new(),
+            page: init,
+            position: crate::settings::get("session", &format!("{init}-paned")).unwrap_or(200),
+            selected: Self::session(init, "selected"),
+            sort,
+            tasks,
+            visibility,
         };
 
         let widgets = view_output!();
 
+        widgets
+            .tree_view
+            .add_controller(Self::drop_target(sender.clone()));
+
         let selection = widgets.tree_view.selection();
         selection.set_mode(gtk::SelectionMode::Multiple);
         selection.connect_changed(move |_| {
@@ -189,11 +465,17 @@ impl relm4::Component for Model {
         use MsgInput::*;
 
         match msg {
+            Burndown(burndown) => self.update_burndown(widgets, burndown),
+            Drop(ids, x, y, replace) => self.drop_tasks(widgets, sender, ids, (x, y), replace),
+            Position(position) => self.position = position,
+            Select(filter) => self.select(widgets, &filter),
             SelectionChange => {
                 let mut filters = Vec::new();
 
                 let (paths, _) = widgets.tree_view.selection().selected_rows();
 
+                self.describe(widgets, &paths);
+
                 for path in paths {
                     match self.filters.get(&path) {
                         Some(value) => filters.push(value.clone()),
@@ -205,15 +487,44 @@ impl relm4::Component for Model {
             }
             UpdateFilters(filters) => self.update_filters(widgets, filters),
             UpdateTasks(tasks) => self.update_tasks(tasks),
+            Visibility(visibility) => {
+                sender.output(MsgOutput::Visibility(visibility)).ok();
+            }
         }
     }
 
+    fn shutdown(&mut self, widgets: &mut Self::Widgets, _: relm4::Sender<Self::Output>) {
+        let (collapsed, selected) = self.state(widgets);
+
+        crate::settings::set_all(
+            "session",
+            &[
+                (
+                    format!("{}-collapsed", self.page).as_str(),
+                    collapsed.join(" "),
+                ),
+                (
+                    format!("{}-paned", self.page).as_str(),
+                    self.position.to_string(),
+                ),
+                (
+                    format!("{}-selected", self.page).as_str(),
+                    selected.join(" "),
+                ),
+            ],
+        );
+    }
+
     view! {
         gtk::Paned {
             set_orientation: gtk::Orientation::Horizontal,
-            set_position: 200,
+            set_position: model.position,
             set_wide_handle: true,
 
+            connect_position_notify[sender] => move |this| {
+                sender.input(MsgInput::Position(this.position()));
+            },
+
             #[wrap(Some)]
             set_start_child = &gtk::ScrolledWindow {
                 set_policy: (gtk::PolicyType::Never, gtk::PolicyType::Automatic),
@@ -222,6 +533,7 @@ impl relm4::Component for Model {
                 gtk::TreeView {
                     set_enable_tree_lines: true,
                     set_headers_visible: false,
+                    update_property: &[gtk::accessible::Property::Label(&Self::label(model.page))],
                     #[wrap(Some)]
                     #[name = "store"]
                     set_model = &gtk::TreeStore::new(&columns),
@@ -230,9 +542,73 @@ impl relm4::Component for Model {
                 },
             },
             #[wrap(Some)]
-            set_end_child = &gtk::ScrolledWindow {
-                set_child: Some(model.tasks.widget()),
+            set_end_child = &gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+
+                gtk::Box {
+                    set_halign: gtk::Align::End,
+                    set_orientation: gtk::Orientation::Horizontal,
+
+                    append: model.visibility.widget(),
+                    append: model.sort.widget(),
+                },
+                #[name = "burndown"]
+                gtk::Expander {
+                    set_label: Some(&gettext("Burndown")),
+                    set_visible: false,
+
+                    #[wrap(Some)]
+                    set_child = &gtk::Box {
+                        set_orientation: gtk::Orientation::Vertical,
+                        set_spacing: 5,
+
+                        append: model.burndown.widget(),
+                        #[name = "burndown_summary"]
+                        gtk::Label {
+                            set_xalign: 0.,
+                        },
+                    },
+                },
+                append: model.tasks.widget(),
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::widgets::filter::*;
+
+    #[gtk::test]
+    fn accessibility() {
+        use relm4::Component as _;
+        use relm4::ComponentController as _;
+
+        let filter = Model::builder().launch("projects").detach();
+        filter.emit(MsgInput::UpdateFilters(vec![
+            ("+home".to_string(), (1, 4)),
+            ("+work".to_string(), (0, 2)),
+        ]));
+        filter.emit(MsgInput::Select("+work".to_string()));
+        crate::widgets::flush();
+
+        let tree_view = filter.widgets().tree_view.clone();
+
+        assert!(gtk::test_accessible_has_role(
+            &tree_view,
+            gtk::AccessibleRole::TreeGrid
+        ));
+        assert_eq!(
+            crate::widgets::check_property(&tree_view, gtk::AccessibleProperty::Label, "Projects"),
+            Ok(())
+        );
+        assert_eq!(
+            crate::widgets::check_property(
+                &tree_view,
+                gtk::AccessibleProperty::Description,
+                "+work, 0 of 2 done"
+            ),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use gtk::prelude::*;
+
+type Completions = std::collections::BTreeMap<chrono::NaiveDate, usize>;
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Click(f64, f64),
+    Select(Option<chrono::NaiveDate>),
+    Set(Completions),
+}
+
+#[derive(Debug)]
+pub enum MsgOutput {
+    Clicked(chrono::NaiveDate),
+}
+
+#[derive(Default)]
+struct State {
+    completions: Completions,
+    selected: Option<chrono::NaiveDate>,
+}
+
+pub struct Model {
+    drawing_area: gtk::DrawingArea,
+    state: std::rc::Rc<std::cell::RefCell<State>>,
+}
+
+impl Model {
+    const CELL: f64 = 12.;
+    const WEEKS: i64 = 53;
+
+    fn first_day() -> chrono::NaiveDate {
+        let week_start = crate::application::preferences().week_start;
+        let end = crate::date::end_of_week(crate::date::today(), week_start);
+
+        end - chrono::Duration::days(Self::WEEKS * 7 - 1)
+    }
+
+    fn date_at(x: f64, y: f64) -> Option<chrono::NaiveDate> {
+        let column = (x / Self::CELL).floor() as i64;
+        let row = (y / Self::CELL).floor() as i64;
+
+        if !(0..Self::WEEKS).contains(&column) || !(0..7).contains(&row) {
+            return None;
+        }
+
+        let date = Self::first_day() + chrono::Duration::days(column * 7 + row);
+
+        (date <= crate::date::today()).then_some(date)
+    }
+
+    fn color(count: usize) -> (f64, f64, f64) {
+        match count {
+            0 => (0.85, 0.85, 0.85),
+            1 => (0.73, 0.85, 0.97),
+            2..=3 => (0.5, 0.72, 0.94),
+            4..=5 => (0.27, 0.6, 0.91),
+            _ => (0.1, 0.4, 0.75),
+        }
+    }
+
+    fn draw(state: &State, context: &gtk::cairo::Context) -> Result<(), gtk::cairo::Error> {
+        let first = Self::first_day();
+        let today = crate::date::today();
+
+        for n in 0..Self::WEEKS * 7 {
+            let date = first + chrono::Duration::days(n);
+
+            if date > today {
+                break;
+            }
+
+            let x = (n / 7) as f64 * Self::CELL;
+            let y = (n % 7) as f64 * Self::CELL;
+            let count = state.completions.get(&date).copied().unwrap_or_default();
+            let (r, g, b) = Self::color(count);
+
+            context.set_source_rgb(r, g, b);
+            context.rectangle(x, y, Self::CELL - 2., Self::CELL - 2.);
+            context.fill()?;
+
+            if state.selected == Some(date) {
+                context.set_source_rgb(1., 0.5, 0.3);
+                context.set_line_width(2.);
+                context.rectangle(x + 1., y + 1., Self::CELL - 4., Self::CELL - 4.);
+                context.stroke()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn tooltip(state: &State, date: chrono::NaiveDate) -> String {
+        use crate::i18n::ngettext;
+
+        let count = state.completions.get(&date).copied().unwrap_or_default();
+
+        ngettext(
+            "{count} task completed on {date}",
+            "{count} tasks completed on {date}",
+            count as u32,
+        )
+        .replace("{count}", &count.to_string())
+        .replace("{date}", &crate::date::format(date))
+    }
+}
+
+impl relm4::SimpleComponent for Model {
+    type Init = ();
+    type Input = MsgInput;
+    type Output = MsgOutput;
+    type Root = gtk::DrawingArea;
+    type Widgets = ();
+
+    fn init_root() -> Self::Root {
+        gtk::DrawingArea::builder()
+            .accessible_role(gtk::AccessibleRole::Img)
+            .build()
+    }
+
+    fn init(
+        _: Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        use crate::i18n::gettext;
+
+        root.set_content_width((Self::CELL * Self::WEEKS as f64) as i32);
+        root.set_content_height((Self::CELL * 7.) as i32);
+        root.set_halign(gtk::Align::Center);
+        root.set_has_tooltip(true);
+        root.update_property(&[gtk::accessible::Property::Label(&gettext(
+            "Completions over the last year",
+        ))]);
+
+        let model = Self {
+            drawing_area: root.clone(),
+            state: std::rc::Rc::new(std::cell::RefCell::new(State::default())),
+        };
+
+        let state = model.state.clone();
+        root.set_draw_func(move |_, context, _, _| {
+            Self::draw(&state.borrow(), context).ok();
+        });
+
+        let state = model.state.clone();
+        root.connect_query_tooltip(move |_, x, y, _, tooltip| {
+            let Some(date) = Self::date_at(x.into(), y.into()) else {
+                return false;
+            };
+
+            tooltip.set_text(Some(&Self::tooltip(&state.borrow(), date)));
+
+            true
+        });
+
+        let gesture = gtk::GestureClick::new();
+        gesture.connect_pressed(move |_, _, x, y| {
+            sender.input(MsgInput::Click(x, y));
+        });
+        root.add_controller(gesture);
+
+        relm4::ComponentParts { model, widgets: () }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: relm4::ComponentSender<Self>) {
+        match msg {
+            MsgInput::Click(x, y) => {
+                if let Some(date) = Self::date_at(x, y) {
+                    sender.output(MsgOutput::Clicked(date)).ok();
+                }
+            }
+            MsgInput::Select(date) => {
+                self.state.borrow_mut().selected = date;
+                self.drawing_area.queue_draw();
+            }
+            MsgInput::Set(completions) => {
+                self.state.borrow_mut().completions = completions;
+                self.drawing_area.queue_draw();
+            }
+        }
+    }
+}
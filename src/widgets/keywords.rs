@@ -2,6 +2,8 @@
 
 use gtk::prelude::*;
 
+use crate::i18n::gettext;
+
 #[derive(Debug)]
 pub enum MsgInput {
     Add,
@@ -226,10 +228,12 @@ impl relm4::Component for Model {
             gtk::ActionBar {
                 pack_start = &gtk::Button {
                     set_icon_name: "list-add",
+                    set_tooltip_text: Some(&gettext("Add")),
                     connect_clicked => MsgInput::Add,
                 },
                 pack_start = &gtk::Button {
                     set_icon_name: "list-remove",
+                    set_tooltip_text: Some(&gettext("Remove")),
                     connect_clicked => MsgInput::Delete,
                 },
             },
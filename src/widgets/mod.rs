@@ -1,9 +1,45 @@
+pub mod bulk;
 pub mod calendar;
+pub mod chart;
 pub mod circle;
 pub mod filter;
+pub mod heatmap;
 pub mod keywords;
 pub mod priority;
 pub mod recurrence;
+pub mod sort;
 pub mod tags;
 pub mod task;
 pub mod tasks;
+pub mod visibility;
+
+#[cfg(test)]
+pub fn check_property(
+    accessible: &impl gtk::prelude::IsA<gtk::Accessible>,
+    property: gtk::AccessibleProperty,
+    value: &str,
+) -> Result<(), String> {
+    use gtk::glib::translate::{FromGlibPtrFull as _, IntoGlib as _, ToGlibPtr as _};
+
+    let value = std::ffi::CString::new(value).unwrap();
+    let error = unsafe {
+        gtk::ffi::gtk_test_accessible_check_property(
+            accessible.as_ref().to_glib_none().0,
+            property.into_glib(),
+            value.as_ptr(),
+        )
+    };
+
+    if error.is_null() {
+        Ok(())
+    } else {
+        Err(unsafe { String::from_glib_full(error) })
+    }
+}
+
+#[cfg(test)]
+pub fn flush() {
+    let context = gtk::glib::MainContext::default();
+
+    while context.iteration(false) {}
+}
@@ -1,5 +1,7 @@
 use gtk::prelude::*;
 
+use crate::i18n::gettext;
+
 #[derive(Debug)]
 pub enum MsgInput {
     Set(todo_txt::Priority),
@@ -123,7 +125,7 @@ impl relm4::Component for Model {
                 },
                 gtk::Button {
                     set_label: "…",
-                    set_tooltip_text: Some("More"),
+                    set_tooltip_text: Some(&gettext("More")),
 
                     connect_clicked => MsgInput::More,
                 },
@@ -132,7 +134,7 @@ impl relm4::Component for Model {
                     set_active: model.priority == 26,
                     set_group: Some(&a),
                     set_label: "Z",
-                    set_tooltip_text: Some("No priority"),
+                    set_tooltip_text: Some(&gettext("No priority")),
 
                     connect_clicked[sender] => move |_| {
                         sender.output(MsgOutput::Updated(26.into())).ok();
@@ -1,5 +1,7 @@
 use gtk::prelude::*;
 
+use crate::i18n::gettext;
+
 #[derive(Debug)]
 pub enum MsgInput {
     Update,
@@ -112,27 +114,27 @@ impl relm4::SimpleComponent for Model {
                 set_orientation: gtk::Orientation::Horizontal,
                 append: group = &gtk::ToggleButton::with_binding(&model.day) {
                     set_label: "d",
-                    set_tooltip_text: Some("Day"),
+                    set_tooltip_text: Some(&gettext("Day")),
 
                     connect_toggled => MsgInput::Update,
                 },
                 gtk::ToggleButton::with_binding(&model.week) {
                     set_label: "w",
-                    set_tooltip_text: Some("Week"),
+                    set_tooltip_text: Some(&gettext("Week")),
                     set_group: Some(&group),
 
                     connect_toggled => MsgInput::Update,
                 },
                 gtk::ToggleButton::with_binding(&model.month) {
                     set_label: "m",
-                    set_tooltip_text: Some("Month"),
+                    set_tooltip_text: Some(&gettext("Month")),
                     set_group: Some(&group),
 
                     connect_toggled => MsgInput::Update,
                 },
                 gtk::ToggleButton::with_binding(&model.year) {
                     set_label: "y",
-                    set_tooltip_text: Some("Year"),
+                    set_tooltip_text: Some(&gettext("Year")),
                     set_group: Some(&group),
 
                     connect_toggled => MsgInput::Update,
@@ -140,8 +142,8 @@ impl relm4::SimpleComponent for Model {
                 gtk::CheckButton::with_binding(&model.strict) {
                     set_halign: gtk::Align::Center,
                     set_hexpand: true,
-                    set_label: Some("Strict"),
-                    set_tooltip_text: Some("Use real due date as offset, not today"),
+                    set_label: Some(&gettext("Strict")),
+                    set_tooltip_text: Some(&gettext("Use real due date as offset, not today")),
 
                     connect_toggled => MsgInput::Update,
                 },
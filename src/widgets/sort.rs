@@ -0,0 +1,126 @@
+use gtk::prelude::*;
+
+use crate::i18n::gettext;
+use crate::tasks::sort::{Group, Key};
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Changed,
+}
+
+pub struct Model {
+    page: &'static str,
+    sort: crate::tasks::Sort,
+}
+
+impl Model {
+    fn dropdown<T: PartialEq>(all: &[T], label: fn(&T) -> String, selected: &T) -> gtk::DropDown {
+        let labels = all.iter().map(label).collect::<Vec<_>>();
+        let dropdown =
+            gtk::DropDown::from_strings(&labels.iter().map(String::as_str).collect::<Vec<_>>());
+
+        if let Some(position) = all.iter().position(|x| x == selected) {
+            dropdown.set_selected(position as u32);
+        }
+
+        dropdown
+    }
+
+    fn changed(&mut self, widgets: &ModelWidgets, sender: relm4::ComponentSender<Self>) {
+        let key = |dropdown: &gtk::DropDown| {
+            Key::ALL
+                .get(dropdown.selected() as usize)
+                .copied()
+                .unwrap_or_default()
+        };
+
+        self.sort = crate::tasks::Sort {
+            primary: key(&widgets.primary),
+            secondary: key(&widgets.secondary),
+            group: Group::ALL
+                .get(widgets.group.selected() as usize)
+                .copied()
+                .unwrap_or_default(),
+        };
+
+        crate::settings::set("sort", self.page, self.sort);
+        sender.output(self.sort).ok();
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = (&'static str, crate::tasks::Sort);
+    type Input = MsgInput;
+    type Output = crate::tasks::Sort;
+
+    fn init(
+        (page, default): Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let sort = crate::settings::get("sort", page).unwrap_or(default);
+
+        let model = Self { page, sort };
+
+        let widgets = view_output!();
+
+        sender.output(sort).ok();
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        _: &Self::Root,
+    ) {
+        match msg {
+            MsgInput::Changed => self.changed(widgets, sender),
+        }
+    }
+
+    view! {
+        gtk::MenuButton {
+            set_halign: gtk::Align::End,
+            set_icon_name: "view-sort-ascending",
+            set_tooltip_text: Some(&gettext("Sort and group")),
+
+            #[wrap(Some)]
+            set_popover = &gtk::Popover {
+                gtk::Grid {
+                    set_column_spacing: 10,
+                    set_row_spacing: 5,
+
+                    attach[0, 0, 1, 1] = &gtk::Label {
+                        set_text: &gettext("Sort by"),
+                        set_xalign: 1.,
+                    },
+                    #[name = "primary"]
+                    attach[1, 0, 1, 1] = &Self::dropdown(&Key::ALL, Key::label, &model.sort.primary) {
+                        connect_selected_notify => MsgInput::Changed,
+                    },
+                    attach[0, 1, 1, 1] = &gtk::Label {
+                        set_text: &gettext("Then by"),
+                        set_xalign: 1.,
+                    },
+                    #[name = "secondary"]
+                    attach[1, 1, 1, 1] = &Self::dropdown(&Key::ALL, Key::label, &model.sort.secondary) {
+                        connect_selected_notify => MsgInput::Changed,
+                    },
+                    attach[0, 2, 1, 1] = &gtk::Label {
+                        set_text: &gettext("Group by"),
+                        set_xalign: 1.,
+                    },
+                    #[name = "group"]
+                    attach[1, 2, 1, 1] = &Self::dropdown(&Group::ALL, Group::label, &model.sort.group) {
+                        connect_selected_notify => MsgInput::Changed,
+                    },
+                },
+            },
+        }
+    }
+}
@@ -10,30 +10,38 @@ pub enum Type {
 
 #[derive(Debug)]
 pub enum MsgInput {
+    Apply(Vec<usize>, crate::tasks::Change),
     Complete(Box<crate::tasks::Task>),
+    Drop(Vec<usize>, String, bool),
     Edit(Box<crate::tasks::Task>),
+    Focus(Box<crate::tasks::Task>),
+    Select(String),
     UpdateFilters(Vec<String>),
     Update,
+    Visibility(crate::tasks::Visibility),
 }
 
 #[derive(Debug)]
 pub enum MsgOutput {
+    Apply(Vec<usize>, crate::tasks::Change),
     Complete(Box<crate::tasks::Task>),
     Edit(Box<crate::tasks::Task>),
+    Focus(Box<crate::tasks::Task>),
 }
 
 pub struct Model {
     tag: Type,
     filter: relm4::Controller<super::filter::Model>,
+    visibility: crate::tasks::Visibility,
 }
 
 impl Model {
     fn update_tags(&self) {
         let list = crate::application::tasks();
         let tags = match self.tag {
-            Type::Projects => list.projects(),
-            Type::Contexts => list.contexts(),
-            Type::Hashtags => list.hashtags(),
+            Type::Projects => list.projects(&self.visibility),
+            Type::Contexts => list.contexts(&self.visibility),
+            Type::Hashtags => list.hashtags(&self.visibility),
         };
 
         let tags = tags
@@ -49,15 +57,7 @@ impl Model {
     fn progress(&self, list: &crate::tasks::List, current: &str) -> (u32, u32) {
         list.tasks
             .iter()
-            .filter(|x| {
-                for tag in self.tags(x) {
-                    if tag == current || tag.starts_with(&format!("{current}-")) {
-                        return true;
-                    }
-                }
-
-                false
-            })
+            .filter(|x| self.tags(x).iter().any(|tag| Self::matches(tag, current)))
             .fold((0, 0), |(mut done, total), x| {
                 if x.finished {
                     done += 1;
@@ -67,9 +67,34 @@ impl Model {
             })
     }
 
+    fn matches(tag: &str, current: &str) -> bool {
+        tag == current || tag.starts_with(&format!("{current}-"))
+    }
+
+    fn update_burndown(&self, filters: &[String]) {
+        let burndown = match (self.tag, filters) {
+            (Type::Projects, [project]) => {
+                let list = crate::application::tasks();
+                let tasks = list
+                    .tasks
+                    .iter()
+                    .filter(|x| self.tags(x).iter().any(|tag| Self::matches(tag, project)))
+                    .collect::<Vec<_>>();
+
+                Some(crate::tasks::burndown::Burndown::new(
+                    &tasks,
+                    crate::date::today(),
+                ))
+            }
+            _ => None,
+        };
+
+        self.filter
+            .emit(crate::widgets::filter::MsgInput::Burndown(burndown));
+    }
+
     fn update_tasks(&self, filters: &[String]) {
         let today = crate::date::today();
-        let preferences = crate::application::preferences();
         let list = crate::application::tasks();
 
         let tasks = list
@@ -78,12 +103,9 @@ impl Model {
             .filter(|x| {
                 let tags = self.tags(x);
 
-                (preferences.done || !x.finished)
-                    && !tags.is_empty()
+                !tags.is_empty()
                     && Self::has_filter(tags, filters)
-                    && (preferences.defered
-                        || x.threshold_date.is_none()
-                        || x.threshold_date.unwrap() <= today)
+                    && self.visibility.matches(x, today)
             })
             .cloned()
             .collect();
@@ -128,16 +150,34 @@ impl relm4::SimpleComponent for Model {
     ) -> relm4::ComponentParts<Self> {
         use relm4::Component as _;
 
+        let page = match init {
+            Type::Projects => "projects",
+            Type::Contexts => "contexts",
+            Type::Hashtags => "tags",
+        };
+
         let filter =
             super::filter::Model::builder()
-                .launch(())
+                .launch(page)
                 .forward(sender.input_sender(), |output| match output {
+                    super::filter::MsgOutput::Apply(ids, change) => MsgInput::Apply(ids, change),
                     super::filter::MsgOutput::Complete(task) => MsgInput::Complete(task),
+                    super::filter::MsgOutput::Drop(ids, tag, replace) => {
+                        MsgInput::Drop(ids, tag, replace)
+                    }
                     super::filter::MsgOutput::Edit(task) => MsgInput::Edit(task),
                     super::filter::MsgOutput::Filters(filters) => MsgInput::UpdateFilters(filters),
+                    super::filter::MsgOutput::Focus(task) => MsgInput::Focus(task),
+                    super::filter::MsgOutput::Visibility(visibility) => {
+                        MsgInput::Visibility(visibility)
+                    }
                 });
 
-        let model = Self { tag: init, filter };
+        let model = Self {
+            tag: init,
+            filter,
+            visibility: crate::tasks::Visibility::default(),
+        };
 
         let widgets = view_output!();
 
@@ -148,17 +188,51 @@ impl relm4::SimpleComponent for Model {
         use MsgInput::*;
 
         match msg {
+            Apply(ids, change) => {
+                sender.output(MsgOutput::Apply(ids, change)).ok();
+            }
             Complete(task) => {
                 sender.output(MsgOutput::Complete(task)).ok();
             }
+            Drop(ids, tag, replace) => {
+                let sigil = match self.tag {
+                    Type::Projects => '+',
+                    Type::Contexts => '@',
+                    Type::Hashtags => '#',
+                };
+                let tag = format!("{sigil}{tag}");
+
+                let change = if replace {
+                    crate::tasks::Change::MoveTag(tag)
+                } else {
+                    crate::tasks::Change::AddTag(tag)
+                };
+
+                sender.output(MsgOutput::Apply(ids, change)).ok();
+            }
             Edit(task) => {
                 sender.output(MsgOutput::Edit(task)).ok();
             }
+            Focus(task) => {
+                sender.output(MsgOutput::Focus(task)).ok();
+            }
+            Select(tag) => self
+                .filter
+                .emit(crate::widgets::filter::MsgInput::Select(tag)),
             Update => {
                 self.update_tags();
                 self.update_tasks(&[]);
+                self.update_burndown(&[]);
+            }
+            UpdateFilters(filters) => {
+                self.update_tasks(&filters);
+                self.update_burndown(&filters);
+            }
+            Visibility(visibility) => {
+                self.visibility = visibility;
+                self.update_tags();
+                self.update_tasks(&[]);
             }
-            UpdateFilters(filters) => self.update_tasks(&filters),
         }
     }
 
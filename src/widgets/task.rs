@@ -1,39 +1,308 @@
 use gtk::prelude::*;
 
+use crate::i18n::gettext;
+
 #[derive(Debug)]
 pub enum MsgInput {
+    Action(String, Option<String>),
     Click,
+    Focus,
+    Menu(Option<(f64, f64)>),
+    Set(Box<crate::tasks::Task>),
     Toggle,
 }
 #[derive(Debug)]
 pub enum MsgOutput {
+    Apply(Vec<usize>, crate::tasks::Change),
     Complete(Box<crate::tasks::Task>),
     Edit(Box<crate::tasks::Task>),
+    Focus(Box<crate::tasks::Task>),
 }
 
 pub struct Model {
+    actions: gtk::gio::SimpleActionGroup,
     task: crate::tasks::Task,
     circle: relm4::Controller<crate::widgets::circle::Model>,
+    menu: gtk::PopoverMenu,
 }
 
 impl Model {
-    fn date_alias(&self, date: chrono::NaiveDate) -> String {
+    fn set(&mut self, widgets: &ModelWidgets, root: &gtk::Box, task: crate::tasks::Task) {
+        use crate::tasks::Markup as _;
+        use relm4::ComponentController as _;
+
         let today = crate::date::today();
 
-        if date == today {
-            String::from("today")
-        } else if Some(date) == today.pred_opt() {
-            String::from("yesterday")
-        } else if Some(date) == today.succ_opt() {
-            String::from("tomorrow")
+        self.task = task;
+        self.circle
+            .emit(crate::widgets::circle::MsgInput::Set(Box::new(
+                self.task.clone(),
+            )));
+
+        let mut classes = vec!["task".to_string()];
+
+        if !self.task.priority.is_lowest() {
+            let priority = (b'a' + u8::from(self.task.priority.clone())) as char;
+            classes.push(format!("pri_{priority}"));
+        }
+
+        root.set_css_classes(&classes.iter().map(String::as_str).collect::<Vec<_>>());
+
+        widgets.check.set_active(self.task.finished);
+        widgets.subject.set_markup(&self.task.markup_subject());
+
+        widgets.note.set_visible(self.task.has_note());
+        widgets
+            .note_label
+            .set_markup(&self.task.note.markup().unwrap_or_default());
+
+        widgets.keywords.set_visible(!self.task.tags.is_empty());
+        widgets.keywords_label.set_text(
+            &self
+                .task
+                .tags
+                .iter()
+                .map(|(k, v)| format!("{k}: {v}"))
+                .collect::<Vec<_>>()
+                .join(" · "),
+        );
+
+        let threshold = self.task.threshold_date.map(|x| {
+            gettext("Deferred until {date}").replace("{date}", &crate::date::alias(x, today))
+        });
+        widgets.threshold_label.set_visible(threshold.is_some());
+        widgets
+            .threshold_label
+            .set_text(&threshold.unwrap_or_default());
+
+        self.enable("links", !self.task.links().is_empty());
+        self.enable("note", self.task.has_note());
+
+        widgets
+            .arrow
+            .set_visible(self.task.threshold_date.is_some() && self.task.due_date.is_some());
+
+        let due = self
+            .task
+            .due_date
+            .map(|x| gettext("due {date}").replace("{date}", &crate::date::alias(x, today)));
+        widgets.due_label.set_visible(due.is_some());
+        widgets.due_label.set_text(&due.unwrap_or_default());
+
+        if self.task.due_date.is_some_and(|due| due < today) {
+            widgets.due_label.add_css_class("past");
         } else {
-            date.format("%Y-%m-%d").to_string()
+            widgets.due_label.remove_css_class("past");
+        }
+
+        root.update_property(&[
+            gtk::accessible::Property::Label(&self.task.subject),
+            gtk::accessible::Property::Description(&self.task.description(today)),
+        ]);
+    }
+
+    fn enable(&self, name: &str, enabled: bool) {
+        if let Some(action) = self
+            .actions
+            .lookup_action(name)
+            .and_downcast::<gtk::gio::SimpleAction>()
+        {
+            action.set_enabled(enabled);
         }
     }
+
+    fn action_group(sender: &relm4::ComponentSender<Self>) -> gtk::gio::SimpleActionGroup {
+        let group = gtk::gio::SimpleActionGroup::new();
+
+        let actions = [
+            ("complete", None),
+            ("copy", None),
+            ("defer", Some(gtk::glib::VariantTy::STRING)),
+            ("delete", None),
+            ("due", Some(gtk::glib::VariantTy::STRING)),
+            ("duplicate", None),
+            ("edit", None),
+            ("flag", None),
+            ("links", None),
+            ("menu", None),
+            ("note", None),
+            ("priority", Some(gtk::glib::VariantTy::STRING)),
+        ];
+
+        for (name, parameter_type) in actions {
+            let action = gtk::gio::SimpleAction::new(name, parameter_type);
+            action.connect_activate(gtk::glib::clone!(
+                #[strong]
+                sender,
+                move |action, parameter| {
+                    let target = parameter.and_then(|x| x.str()).map(str::to_string);
+                    sender.input(MsgInput::Action(action.name().to_string(), target));
+                }
+            ));
+            group.add_action(&action);
+        }
+
+        group
+    }
+
+    fn shortcut_controller() -> gtk::ShortcutController {
+        let controller = gtk::ShortcutController::new();
+
+        for shortcut in crate::shortcuts::group("tasks") {
+            for trigger in &shortcut.triggers {
+                let Some(trigger) = gtk::ShortcutTrigger::parse_string(trigger) else {
+                    continue;
+                };
+
+                let action = gtk::NamedAction::new(&format!("task.{}", shortcut.action));
+                let item = gtk::Shortcut::new(Some(trigger), Some(action));
+
+                if let Some(target) = shortcut.target {
+                    item.set_arguments(Some(&target.to_variant()));
+                }
+
+                controller.add_shortcut(item);
+            }
+        }
+
+        controller
+    }
+
+    fn menu_item(label: &str, action: &str, target: Option<&str>) -> gtk::gio::MenuItem {
+        let item = gtk::gio::MenuItem::new(Some(label), None);
+
+        item.set_action_and_target_value(
+            Some(&format!("task.{action}")),
+            target.map(|x| x.to_variant()).as_ref(),
+        );
+
+        let accel = crate::shortcuts::group("tasks")
+            .into_iter()
+            .find(|x| x.action == action && x.target == target)
+            .and_then(|x| x.triggers.first().cloned());
+
+        if let Some(accel) = accel {
+            item.set_attribute_value("accel", Some(&accel.to_variant()));
+        }
+
+        item
+    }
+
+    fn menu_model(&self) -> gtk::gio::Menu {
+        let item = Self::menu_item;
+        let menu = gtk::gio::Menu::new();
+
+        let section = gtk::gio::Menu::new();
+        let complete = if self.task.finished {
+            gettext("Uncomplete")
+        } else {
+            gettext("Complete")
+        };
+        section.append_item(&item(&complete, "complete", None));
+        let flag = if self.task.flagged {
+            gettext("Unflag")
+        } else {
+            gettext("Flag")
+        };
+        section.append_item(&item(&flag, "flag", None));
+
+        let priority = gtk::gio::Menu::new();
+        for letter in ["A", "B", "C", "D", "E"] {
+            priority.append_item(&item(letter, "priority", Some(letter)));
+        }
+        priority.append_item(&item(&gettext("None"), "priority", Some("none")));
+        section.append_submenu(Some(&gettext("Priority")), &priority);
+        menu.append_section(None, &section);
+
+        let section = gtk::gio::Menu::new();
+        section.append_item(&item(&gettext("Defer 1 day"), "defer", Some("1d")));
+        section.append_item(&item(&gettext("Defer 1 week"), "defer", Some("1w")));
+        section.append_item(&item(&gettext("Defer 1 month"), "defer", Some("1m")));
+        section.append_item(&item(&gettext("Due today"), "due", Some("today")));
+        section.append_item(&item(&gettext("Due tomorrow"), "due", Some("tomorrow")));
+        menu.append_section(None, &section);
+
+        let section = gtk::gio::Menu::new();
+        section.append_item(&item(&gettext("Copy"), "copy", None));
+        section.append_item(&item(&gettext("Open links"), "links", None));
+        section.append_item(&item(&gettext("Open note"), "note", None));
+        section.append_item(&item(&gettext("Edit"), "edit", None));
+        section.append_item(&item(&gettext("Duplicate"), "duplicate", None));
+        section.append_item(&item(&gettext("Delete"), "delete", None));
+        menu.append_section(None, &section);
+
+        menu
+    }
+
+    fn popup(&self, position: Option<(f64, f64)>) {
+        self.menu.set_menu_model(Some(&self.menu_model()));
+
+        let rectangle = position.map(|(x, y)| gtk::gdk::Rectangle::new(x as i32, y as i32, 1, 1));
+        self.menu.set_pointing_to(rectangle.as_ref());
+        self.menu.popup();
+    }
+
+    fn action(
+        &self,
+        widgets: &ModelWidgets,
+        root: &gtk::Box,
+        sender: relm4::ComponentSender<Self>,
+        name: &str,
+        target: Option<&str>,
+    ) {
+        use crate::tasks::Change;
+
+        let change = match (name, target) {
+            ("complete", _) => {
+                sender
+                    .output(MsgOutput::Complete(Box::new(self.task.clone())))
+                    .ok();
+                return;
+            }
+            ("copy", _) => {
+                root.clipboard().set_text(&self.task.to_string());
+                return;
+            }
+            ("edit", _) => {
+                sender
+                    .output(MsgOutput::Edit(Box::new(self.task.clone())))
+                    .ok();
+                return;
+            }
+            ("links", _) => {
+                for link in self.task.links() {
+                    if let Err(err) = gtk::gio::AppInfo::launch_default_for_uri(
+                        &link,
+                        None::<&gtk::gio::AppLaunchContext>,
+                    ) {
+                        log::error!("Unable to open {link}: {err}");
+                    }
+                }
+                return;
+            }
+            ("menu", _) => {
+                self.popup(None);
+                return;
+            }
+            ("note", _) => {
+                widgets.note.popup();
+                return;
+            }
+            _ => match Change::from_action(&self.task, name, target) {
+                Some(change) => change,
+                None => return,
+            },
+        };
+
+        sender
+            .output(MsgOutput::Apply(vec![self.task.id], change))
+            .ok();
+    }
 }
 
 #[relm4::component(pub)]
-impl relm4::SimpleComponent for Model {
+impl relm4::Component for Model {
+    type CommandOutput = ();
     type Init = crate::tasks::Task;
     type Input = MsgInput;
     type Output = MsgOutput;
@@ -43,7 +312,6 @@ impl relm4::SimpleComponent for Model {
         root: Self::Root,
         sender: relm4::ComponentSender<Self>,
     ) -> relm4::ComponentParts<Self> {
-        use crate::tasks::Markup as _;
         use relm4::Component as _;
         use relm4::ComponentController as _;
 
@@ -51,48 +319,97 @@ impl relm4::SimpleComponent for Model {
             .launch(init.clone())
             .detach();
 
-        let model = Self { task: init, circle };
+        let actions = Self::action_group(&sender);
+        root.insert_action_group("task", Some(&actions));
+        root.add_controller(Self::shortcut_controller());
+
+        let menu = gtk::PopoverMenu::from_model(None::<&gtk::gio::MenuModel>);
+        menu.set_has_arrow(false);
+        menu.set_halign(gtk::Align::Start);
+        menu.set_parent(&root);
+
+        let mut model = Self {
+            actions,
+            task: crate::tasks::Task::new(),
+            circle,
+            menu,
+        };
 
         let widgets = view_output!();
 
-        if let Some(due) = model.task.due_date {
-            let today = crate::date::today();
+        model.set(&widgets, &root, init);
 
-            if due < today {
-                widgets.due_label.add_css_class("past");
-            }
-        }
+        let focus = gtk::EventControllerFocus::new();
+        focus.connect_enter(gtk::glib::clone!(
+            #[strong]
+            sender,
+            move |_| sender.input(MsgInput::Focus)
+        ));
+        root.add_controller(focus);
 
         let gesture = gtk::GestureClick::new();
-        gesture.connect_pressed(move |_, n_press, _, _| {
-            if n_press == 2 {
-                sender.input(MsgInput::Click);
+        gesture.connect_pressed(gtk::glib::clone!(
+            #[strong]
+            sender,
+            move |_, n_press, _, _| {
+                if n_press == 2 {
+                    sender.input(MsgInput::Click);
+                }
             }
-        });
+        ));
         root.add_controller(gesture);
 
-        if !model.task.priority.is_lowest() {
-            let priority = (b'a' + u8::from(model.task.priority.clone())) as char;
-            root.add_css_class(&format!("pri_{priority}"));
-        }
+        let gesture = gtk::GestureClick::new();
+        gesture.set_button(gtk::gdk::BUTTON_SECONDARY);
+        gesture.connect_pressed(move |_, _, x, y| {
+            sender.input(MsgInput::Menu(Some((x, y))));
+        });
+        root.add_controller(gesture);
 
         relm4::ComponentParts { model, widgets }
     }
 
-    fn update(&mut self, msg: Self::Input, sender: relm4::ComponentSender<Self>) {
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
         match msg {
-            MsgInput::Toggle => sender
-                .output(MsgOutput::Complete(Box::new(self.task.clone())))
-                .ok(),
-            MsgInput::Click => sender
-                .output(MsgOutput::Edit(Box::new(self.task.clone())))
-                .ok(),
-        };
+            MsgInput::Action(name, target) => {
+                self.action(widgets, root, sender, &name, target.as_deref())
+            }
+            MsgInput::Click => {
+                sender
+                    .output(MsgOutput::Edit(Box::new(self.task.clone())))
+                    .ok();
+            }
+            MsgInput::Focus => {
+                sender
+                    .output(MsgOutput::Focus(Box::new(self.task.clone())))
+                    .ok();
+            }
+            MsgInput::Menu(position) => self.popup(position),
+            MsgInput::Set(task) => self.set(widgets, root, *task),
+            MsgInput::Toggle => {
+                if widgets.check.is_active() != self.task.finished {
+                    sender
+                        .output(MsgOutput::Complete(Box::new(self.task.clone())))
+                        .ok();
+                }
+            }
+        }
+    }
+
+    fn shutdown(&mut self, _: &mut Self::Widgets, _: relm4::Sender<Self::Output>) {
+        self.menu.unparent();
     }
 
     view! {
-        gtk::Box {
+        gtk::Box::builder().accessible_role(gtk::AccessibleRole::Group).build() -> gtk::Box {
             add_css_class: "task",
+            set_focusable: true,
             set_orientation: gtk::Orientation::Horizontal,
             set_spacing: 5,
 
@@ -104,13 +421,13 @@ impl relm4::SimpleComponent for Model {
                     set_orientation: gtk::Orientation::Horizontal,
                     set_spacing: 5,
 
+                    #[name = "check"]
                     gtk::CheckButton {
-                        set_active: model.task.finished,
-
+                        update_property: &[gtk::accessible::Property::Label(&gettext("Complete"))],
                         connect_toggled => MsgInput::Toggle,
                     },
+                    #[name = "subject"]
                     gtk::Label {
-                        set_markup: model.task.markup_subject().as_str(),
                         set_xalign: 0.,
                     },
                 },
@@ -118,29 +435,28 @@ impl relm4::SimpleComponent for Model {
                     set_orientation: gtk::Orientation::Horizontal,
                     set_spacing: 5,
 
+                    #[name = "note"]
                     gtk::MenuButton {
                         set_icon_name: "text-x-generic",
-                        set_visible: model.task.has_note(),
+                        update_property: &[gtk::accessible::Property::Label(&gettext("Open note"))],
 
                         #[wrap(Some)]
                         set_popover = &gtk::Popover {
                             set_position: gtk::PositionType::Right,
 
+                            #[name = "note_label"]
                             gtk::Label {
-                                set_markup?: &model.task.note.markup(),
                             },
                         },
                     },
                     #[name="keywords"]
                     gtk::Box {
-                        set_visible: !model.task.tags.is_empty(),
-
                         gtk::Image {
                             set_icon_name: Some("mail-attachment"),
+                            update_property: &[gtk::accessible::Property::Label(&gettext("Attributes"))],
                         },
                         #[name="keywords_label"]
                         gtk::Label {
-                            set_text: &model.task.tags.iter().map(|(k, v)| format!("{k}: {v}")).collect::<Vec<_>>().join(" · "),
                         },
                     },
                     gtk::Box {
@@ -150,20 +466,18 @@ impl relm4::SimpleComponent for Model {
                         set_spacing: 5,
                         set_valign: gtk::Align::End,
 
+                        #[name = "threshold_label"]
                         gtk::Label {
                             add_css_class: "threshold",
-                            set_text?: &model.task.threshold_date.map(|x| format!("Deferred until {}", model.date_alias(x))),
-                            set_visible: model.task.threshold_date.is_some(),
                         },
+                        #[name = "arrow"]
                         gtk::Label {
                             set_text: " ➡ ",
-                            set_visible: model.task.threshold_date.is_some() && model.task.due_date.is_some(),
+                            update_state: &[gtk::accessible::State::Hidden(true)],
                         },
                         #[name="due_label"]
                         gtk::Label {
                             add_css_class: "due",
-                            set_text?: &model.task.due_date.map(|x| format!("due {}", model.date_alias(x))),
-                            set_visible: model.task.due_date.is_some(),
                         },
                     },
                 },
@@ -172,3 +486,59 @@ impl relm4::SimpleComponent for Model {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::widgets::task::*;
+
+    #[gtk::test]
+    fn accessibility() {
+        use relm4::Component as _;
+        use relm4::ComponentController as _;
+
+        let tomorrow = crate::date::today().succ_opt().unwrap();
+        let mut task = format!("(A) Call Mom due:{tomorrow}")
+            .parse::<crate::tasks::Task>()
+            .unwrap();
+        task.flagged = true;
+        task.recurrence = Some(todo_txt::task::Recurrence {
+            num: 1,
+            period: todo_txt::task::Period::Week,
+            strict: false,
+        });
+
+        let row = Model::builder().launch(task).detach();
+        let root = row.widget();
+
+        assert!(gtk::test_accessible_has_role(
+            root,
+            gtk::AccessibleRole::Group
+        ));
+        assert_eq!(
+            crate::widgets::check_property(root, gtk::AccessibleProperty::Label, "Call Mom"),
+            Ok(())
+        );
+        assert_eq!(
+            crate::widgets::check_property(
+                root,
+                gtk::AccessibleProperty::Description,
+                "priority A, due tomorrow, flagged, recurring"
+            ),
+            Ok(())
+        );
+
+        row.emit(MsgInput::Set(Box::new(
+            "Call Dad".parse::<crate::tasks::Task>().unwrap(),
+        )));
+        crate::widgets::flush();
+
+        assert_eq!(
+            crate::widgets::check_property(root, gtk::AccessibleProperty::Label, "Call Dad"),
+            Ok(())
+        );
+        assert_eq!(
+            crate::widgets::check_property(root, gtk::AccessibleProperty::Description, ""),
+            Ok(())
+        );
+    }
+}
@@ -1,69 +1,311 @@
 use gtk::prelude::*;
 
+use crate::i18n::gettext;
+
+type Rows =
+    std::collections::HashMap<gtk::ListItem, (gtk::Label, relm4::Controller<super::task::Model>)>;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Row {
+    Header {
+        title: String,
+        summary: Option<String>,
+        collapsed: bool,
+    },
+    Task(crate::tasks::Task),
+}
+
 #[derive(Debug)]
 pub enum MsgInput {
+    Activate(u32),
+    Apply(crate::tasks::Change),
     Map,
+    MaxHeight(i32),
     NeedUpdate,
     Outdated,
+    SelectionChanged,
+    Sort(crate::tasks::Sort),
+    Toggle(String),
+    Unselect,
     Update(Vec<crate::tasks::Task>),
+    Visibility(crate::tasks::Visibility),
 }
 
-#[derive(Default)]
 pub struct Model {
-    children: Vec<relm4::Controller<super::task::Model>>,
-    tasks: Vec<crate::tasks::Task>,
-    outdated: bool,
+    bulk: relm4::Controller<super::bulk::Model>,
+    collapsed: std::collections::HashSet<String>,
+    displayed: Vec<Row>,
     filter: crate::Filter,
+    outdated: bool,
+    selection: gtk::MultiSelection,
+    sort: crate::tasks::Sort,
+    store: gtk::gio::ListStore,
+    tasks: Vec<crate::tasks::Task>,
+    visibility: crate::tasks::Visibility,
 }
 
 impl Model {
-    fn map(&mut self, widgets: &ModelWidgets, sender: &relm4::ComponentSender<Self>) {
-        use relm4::Component as _;
+    fn map(&mut self, widgets: &ModelWidgets) {
         use relm4::ComponentController as _;
 
         self.outdated = false;
-        self.clear(widgets);
 
         widgets.outdated.set_visible(false);
         widgets.outdated.stop();
 
-        if self.tasks.is_empty() {
-            widgets.list_box.set_visible(false);
-            widgets.nothing.set_visible(true);
-            return;
-        }
+        let rows = self
+            .sort
+            .apply(self.tasks.clone(), crate::date::today())
+            .into_iter()
+            .flat_map(|(header, tasks)| {
+                let collapsed = header.as_ref().is_some_and(|x| self.collapsed.contains(x));
+                let header = header.map(|title| Row::Header {
+                    summary: self.sort.group.summary(&tasks),
+                    collapsed,
+                    title,
+                });
 
-        widgets.list_box.set_visible(true);
-        widgets.nothing.set_visible(false);
+                header
+                    .into_iter()
+                    .chain(tasks.into_iter().filter(move |_| !collapsed).map(Row::Task))
+            })
+            .collect();
+
+        self.splice(rows);
 
-        let mut sorted_tasks = self.tasks.clone();
-        sorted_tasks.sort();
-        sorted_tasks.reverse();
+        widgets
+            .scrolled_window
+            .set_visible(!self.displayed.is_empty());
+        widgets.nothing.set_visible(self.displayed.is_empty());
 
-        for task in &sorted_tasks {
-            let child = super::task::Model::builder()
-                .launch(task.clone())
-                .forward(sender.output_sender(), std::convert::identity);
+        let count = self.selected().len();
+        self.bulk.emit(super::bulk::MsgInput::Select(count));
+    }
+
+    fn splice(&mut self, tasks: Vec<Row>) {
+        let prefix = self
+            .displayed
+            .iter()
+            .zip(&tasks)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let suffix = self.displayed[prefix..]
+            .iter()
+            .rev()
+            .zip(tasks[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
 
-            widgets.list_box.append(child.widget());
+        let removed = self.displayed.len() - prefix - suffix;
+        let added = tasks[prefix..tasks.len() - suffix]
+            .iter()
+            .cloned()
+            .map(gtk::glib::BoxedAnyObject::new)
+            .collect::<Vec<_>>();
 
-            self.children.push(child);
+        if removed > 0 || !added.is_empty() {
+            self.store.splice(prefix as u32, removed as u32, &added);
         }
+
+        self.displayed = tasks;
     }
 
     fn outdated(&mut self, widgets: &ModelWidgets) {
         self.outdated = true;
-        widgets.list_box.set_visible(false);
+        widgets.scrolled_window.set_visible(false);
         widgets.nothing.set_visible(false);
         widgets.outdated.set_visible(true);
         widgets.outdated.start();
     }
 
-    fn clear(&mut self, widgets: &ModelWidgets) {
-        use relm4::RelmRemoveAllExt as _;
+    fn selected(&self) -> Vec<usize> {
+        Self::selected_ids(&self.selection)
+    }
+
+    fn selected_ids(selection: &gtk::MultiSelection) -> Vec<usize> {
+        let bitset = selection.selection();
 
-        widgets.list_box.remove_all();
-        self.children = Vec::new();
+        (0..bitset.size())
+            .filter_map(|n| Self::id(selection.item(bitset.nth(n as u32))))
+            .collect()
+    }
+
+    fn id(object: Option<gtk::glib::Object>) -> Option<usize> {
+        let object = object.and_downcast::<gtk::glib::BoxedAnyObject>()?;
+
+        match &*object.borrow::<Row>() {
+            Row::Task(task) => Some(task.id),
+            Row::Header { .. } => None,
+        }
+    }
+
+    fn header(object: Option<gtk::glib::Object>) -> Option<String> {
+        let object = object.and_downcast::<gtk::glib::BoxedAnyObject>()?;
+
+        match &*object.borrow::<Row>() {
+            Row::Header { title, .. } => Some(title.clone()),
+            Row::Task(_) => None,
+        }
+    }
+
+    fn drag_source(item: &gtk::ListItem, selection: &gtk::MultiSelection) -> gtk::DragSource {
+        let drag_source = gtk::DragSource::new();
+        drag_source.set_actions(gtk::gdk::DragAction::COPY | gtk::gdk::DragAction::MOVE);
+
+        drag_source.connect_prepare(gtk::glib::clone!(
+            #[weak]
+            item,
+            #[strong]
+            selection,
+            #[upgrade_or]
+            None,
+            move |_, _, _| {
+                let ids = if item.is_selected() {
+                    Self::selected_ids(&selection)
+                } else {
+                    Self::id(item.item()).into_iter().collect()
+                };
+
+                if ids.is_empty() {
+                    return None;
+                }
+
+                let ids = ids
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                Some(gtk::gdk::ContentProvider::for_value(&ids.to_value()))
+            }
+        ));
+
+        drag_source
+    }
+
+    fn navigation() -> gtk::ShortcutController {
+        let controller = gtk::ShortcutController::new();
+
+        for shortcut in crate::shortcuts::group("navigation") {
+            let direction = match shortcut.action {
+                "next" => gtk::DirectionType::Down,
+                "previous" => gtk::DirectionType::Up,
+                _ => continue,
+            };
+
+            for trigger in &shortcut.triggers {
+                let callback = gtk::CallbackAction::new(move |widget, _| {
+                    widget.child_focus(direction);
+                    gtk::glib::Propagation::Stop
+                });
+
+                let trigger = gtk::ShortcutTrigger::parse_string(trigger);
+                controller.add_shortcut(gtk::Shortcut::new(trigger, Some(callback)));
+            }
+        }
+
+        controller
+    }
+
+    fn factory(
+        output: &relm4::Sender<super::task::MsgOutput>,
+        selection: &gtk::MultiSelection,
+    ) -> gtk::SignalListItemFactory {
+        let output = output.clone();
+        let selection = selection.clone();
+        let rows = std::rc::Rc::new(std::cell::RefCell::new(Rows::new()));
+        let factory = gtk::SignalListItemFactory::new();
+
+        factory.connect_setup(gtk::glib::clone!(
+            #[strong]
+            rows,
+            #[strong]
+            output,
+            #[strong]
+            selection,
+            move |_, item| {
+                use relm4::Component as _;
+                use relm4::ComponentController as _;
+
+                let Some(item) = item.downcast_ref::<gtk::ListItem>() else {
+                    return;
+                };
+
+                let row = super::task::Model::builder()
+                    .launch(crate::tasks::Task::new())
+                    .forward(&output, std::convert::identity);
+
+                let header = gtk::Label::new(None);
+                header.add_css_class("group");
+                header.set_xalign(0.);
+
+                let child = gtk::Box::new(gtk::Orientation::Vertical, 0);
+                child.append(&header);
+                child.append(row.widget());
+                child.add_controller(Self::drag_source(item, &selection));
+
+                item.set_child(Some(&child));
+                rows.borrow_mut().insert(item.clone(), (header, row));
+            }
+        ));
+
+        factory.connect_bind(gtk::glib::clone!(
+            #[strong]
+            rows,
+            move |_, item| {
+                use relm4::ComponentController as _;
+
+                let Some(item) = item.downcast_ref::<gtk::ListItem>() else {
+                    return;
+                };
+
+                let Some(object) = item.item().and_downcast::<gtk::glib::BoxedAnyObject>() else {
+                    return;
+                };
+
+                let rows = rows.borrow();
+                let Some((header, row)) = rows.get(item) else {
+                    return;
+                };
+
+                match object.borrow::<Row>().clone() {
+                    Row::Header {
+                        title,
+                        summary,
+                        collapsed,
+                    } => {
+                        let arrow = if collapsed { "▸" } else { "▾" };
+                        let text = match summary {
+                            Some(summary) => format!("{arrow} {title} · {summary}"),
+                            None => format!("{arrow} {title}"),
+                        };
+
+                        header.set_text(&text);
+                        header.set_visible(true);
+                        header.update_state(&[gtk::accessible::State::Expanded(Some(!collapsed))]);
+                        row.widget().set_visible(false);
+                        item.set_activatable(true);
+                        item.set_selectable(false);
+                    }
+                    Row::Task(task) => {
+                        header.set_visible(false);
+                        row.widget().set_visible(true);
+                        item.set_activatable(true);
+                        item.set_selectable(true);
+                        row.emit(super::task::MsgInput::Set(Box::new(task)));
+                    }
+                }
+            }
+        ));
+
+        factory.connect_teardown(move |_, item| {
+            if let Some(item) = item.downcast_ref::<gtk::ListItem>() {
+                rows.borrow_mut().remove(item);
+            }
+        });
+
+        factory
     }
 }
 
@@ -79,10 +321,38 @@ impl relm4::Component for Model {
         root: Self::Root,
         sender: relm4::ComponentSender<Self>,
     ) -> relm4::ComponentParts<Self> {
+        use relm4::Component as _;
+        use relm4::ComponentController as _;
+
+        let bulk =
+            super::bulk::Model::builder()
+                .launch(())
+                .forward(sender.input_sender(), |output| match output {
+                    super::bulk::MsgOutput::Apply(change) => MsgInput::Apply(change),
+                    super::bulk::MsgOutput::Unselect => MsgInput::Unselect,
+                });
+
+        let store = gtk::gio::ListStore::new::<gtk::glib::BoxedAnyObject>();
+        let selection = gtk::MultiSelection::new(Some(store.clone()));
+        let factory = Self::factory(sender.output_sender(), &selection);
+
+        selection.connect_selection_changed(gtk::glib::clone!(
+            #[strong]
+            sender,
+            move |_, _, _| sender.input(MsgInput::SelectionChanged)
+        ));
+
         let model = Self {
+            bulk,
+            collapsed: std::collections::HashSet::new(),
+            displayed: Vec::new(),
             filter: init,
-
-            ..Default::default()
+            outdated: false,
+            selection,
+            sort: crate::tasks::Sort::default(),
+            store,
+            tasks: Vec::new(),
+            visibility: crate::tasks::Visibility::default(),
         };
 
         let widgets = view_output!();
@@ -101,15 +371,53 @@ impl relm4::Component for Model {
         use MsgInput::*;
 
         match msg {
+            Activate(position) => {
+                if let Some(title) = Self::header(self.store.item(position)) {
+                    sender.input(MsgInput::Toggle(title));
+                }
+            }
+            Apply(change) => {
+                sender
+                    .output(super::task::MsgOutput::Apply(self.selected(), change))
+                    .ok();
+            }
             Outdated => self.outdated(widgets),
-            Map => self.map(widgets, &sender),
+            Map => self.map(widgets),
+            MaxHeight(height) => {
+                widgets.scrolled_window.set_max_content_height(height);
+                widgets.scrolled_window.set_propagate_natural_height(true);
+                widgets.scrolled_window.set_vexpand(false);
+            }
             NeedUpdate => {
-                self.tasks = (self.filter)();
+                self.tasks = (self.filter)(&self.visibility);
+
+                if root.is_drawable() {
+                    sender.input(MsgInput::Map);
+                }
+            }
+            SelectionChanged => {
+                use relm4::ComponentController as _;
+
+                let count = self.selected().len();
+                self.bulk.emit(super::bulk::MsgInput::Select(count));
+            }
+            Sort(sort) => {
+                self.sort = sort;
 
                 if root.is_drawable() {
                     sender.input(MsgInput::Map);
                 }
             }
+            Toggle(title) => {
+                if !self.collapsed.remove(&title) {
+                    self.collapsed.insert(title);
+                }
+
+                sender.input(MsgInput::Map);
+            }
+            Unselect => {
+                self.selection.unselect_all();
+            }
             Update(tasks) => {
                 self.tasks = tasks.clone();
 
@@ -117,27 +425,44 @@ impl relm4::Component for Model {
                     sender.input(MsgInput::Map);
                 }
             }
+            Visibility(visibility) => {
+                self.visibility = visibility;
+                sender.input(MsgInput::NeedUpdate);
+            }
         }
     }
 
     view! {
-        gtk::ScrolledWindow {
-            gtk::Box {
-                #[name = "list_box"]
-                gtk::ListBox {
-                    set_hexpand: true,
-                    set_vexpand: true,
-                },
-                #[name = "nothing"]
-                gtk::Label {
-                    set_hexpand: true,
-                    set_text: "Nothing to do :)",
-                    set_vexpand: true,
-                },
-                #[name = "outdated"]
-                gtk::Spinner {
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+
+            #[name = "scrolled_window"]
+            gtk::ScrolledWindow {
+                set_hexpand: true,
+                set_vexpand: true,
+
+                #[name = "list_view"]
+                gtk::ListView {
+                    add_controller: Self::navigation(),
+                    update_property: &[gtk::accessible::Property::Label(&gettext("Tasks"))],
+                    set_factory: Some(&factory),
+                    set_model: Some(&model.selection),
+
+                    connect_activate[sender] => move |_, position| {
+                        sender.input(MsgInput::Activate(position));
+                    },
                 },
             },
+            #[name = "nothing"]
+            gtk::Label {
+                set_hexpand: true,
+                set_text: &gettext("Nothing to do :)"),
+                set_vexpand: true,
+            },
+            #[name = "outdated"]
+            gtk::Spinner {
+            },
+            append: model.bulk.widget(),
 
             connect_map => MsgInput::Map,
         },
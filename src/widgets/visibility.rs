@@ -0,0 +1,102 @@
+use gtk::prelude::*;
+
+use crate::i18n::gettext;
+
+#[derive(Debug)]
+pub enum MsgInput {
+    Changed,
+}
+
+pub struct Model {
+    page: &'static str,
+    visibility: crate::tasks::Visibility,
+}
+
+impl Model {
+    fn changed(&mut self, widgets: &ModelWidgets, sender: relm4::ComponentSender<Self>) {
+        self.visibility = crate::tasks::Visibility {
+            defered: widgets.defered.is_active(),
+            done: widgets.done.is_active() || !Self::has_done(self.page),
+            hidden: widgets.hidden.is_active(),
+        };
+
+        crate::settings::set("visibility", self.page, self.visibility);
+        sender.output(self.visibility).ok();
+    }
+
+    fn has_done(page: &str) -> bool {
+        page != "done"
+    }
+}
+
+#[relm4::component(pub)]
+impl relm4::Component for Model {
+    type CommandOutput = ();
+    type Init = (&'static str, crate::tasks::Visibility);
+    type Input = MsgInput;
+    type Output = crate::tasks::Visibility;
+
+    fn init(
+        (page, default): Self::Init,
+        root: Self::Root,
+        sender: relm4::ComponentSender<Self>,
+    ) -> relm4::ComponentParts<Self> {
+        let mut visibility = crate::settings::get("visibility", page).unwrap_or(default);
+        visibility.done |= !Self::has_done(page);
+
+        let model = Self { page, visibility };
+
+        let widgets = view_output!();
+
+        sender.output(visibility).ok();
+
+        relm4::ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        msg: Self::Input,
+        sender: relm4::ComponentSender<Self>,
+        _: &Self::Root,
+    ) {
+        match msg {
+            MsgInput::Changed => self.changed(widgets, sender),
+        }
+    }
+
+    view! {
+        gtk::MenuButton {
+            set_halign: gtk::Align::End,
+            set_icon_name: "view-reveal-symbolic",
+            set_tooltip_text: Some(&gettext("Visible tasks")),
+
+            #[wrap(Some)]
+            set_popover = &gtk::Popover {
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+
+                    #[name = "done"]
+                    gtk::CheckButton {
+                        set_active: model.visibility.done,
+                        set_visible: Self::has_done(page),
+                        set_label: Some(&gettext("Display done tasks")),
+                        connect_toggled => MsgInput::Changed,
+                    },
+                    #[name = "defered"]
+                    gtk::CheckButton {
+                        set_active: model.visibility.defered,
+                        set_label: Some(&gettext("Display defered tasks")),
+                        connect_toggled => MsgInput::Changed,
+                    },
+                    #[name = "hidden"]
+                    gtk::CheckButton {
+                        set_active: model.visibility.hidden,
+                        set_label: Some(&gettext("Display hidden tasks")),
+                        connect_toggled => MsgInput::Changed,
+                    },
+                },
+            },
+        }
+    }
+}